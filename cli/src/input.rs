@@ -0,0 +1,187 @@
+use anyhow::{anyhow, Result};
+use avocado_schema::core::field::FieldEnum;
+use avocado_schema::visitor::json::from_json;
+use avocado_schema::visitor::stream::{self, json_array, ndjson, RecordError, StreamError};
+use avocado_schema::visitor::validator::{ValidationError, Validator};
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde_json::{Map, Number, Value};
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Format {
+    Json,
+    Ndjson,
+    Yaml,
+    Csv,
+}
+
+impl Format {
+    pub fn detect(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Format::Json),
+            "ndjson" | "jsonl" => Some(Format::Ndjson),
+            "yaml" | "yml" => Some(Format::Yaml),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+pub enum Failure {
+    Parse(String),
+    Invalid(BTreeMap<String, Vec<ValidationError>>),
+}
+
+pub struct Record {
+    pub index: usize,
+    pub line: Option<usize>,
+    pub result: Result<(), Failure>,
+}
+
+pub type Records<'v> = Box<dyn Iterator<Item = Result<Record>> + 'v>;
+
+fn validate(validator: &Validator, index: usize, line: Option<usize>, value: &Value) -> Record {
+    Record {
+        index,
+        line,
+        result: validator
+            .validate(&from_json(validator.schema(), value))
+            .map_err(Failure::Invalid),
+    }
+}
+
+fn streamed(record: Result<stream::Record, StreamError>) -> Result<Record> {
+    let record = record?;
+    Ok(Record {
+        index: record.index,
+        line: Some(record.line),
+        result: record.result.map_err(|e| match e {
+            RecordError::Json(e) => Failure::Parse(e.to_string()),
+            RecordError::Invalid(errors) => Failure::Invalid(errors),
+        }),
+    })
+}
+
+/// A top-level array is validated item by item, unless the schema is an array itself.
+fn json<'v>(validator: &'v Validator, mut reader: Box<dyn BufRead + 'v>) -> Result<Records<'v>> {
+    let is_array = loop {
+        let buffer = reader.fill_buf()?;
+        match buffer.iter().position(|b| !b.is_ascii_whitespace()) {
+            Some(position) => {
+                let is_array = buffer[position] == b'[';
+                reader.consume(position);
+                break is_array;
+            }
+            None if buffer.is_empty() => break false,
+            None => {
+                let length = buffer.len();
+                reader.consume(length);
+            }
+        }
+    };
+
+    if is_array && !matches!(validator.schema(), FieldEnum::Array(_)) {
+        return Ok(Box::new(json_array(validator, reader).map(streamed)));
+    }
+
+    let record = match serde_json::from_reader::<_, Value>(reader) {
+        Ok(value) => validate(validator, 0, None, &value),
+        Err(e) => Record {
+            index: 0,
+            line: Some(e.line()),
+            result: Err(Failure::Parse(e.to_string())),
+        },
+    };
+    Ok(Box::new(std::iter::once(Ok(record))))
+}
+
+fn yaml<'v>(validator: &'v Validator, reader: Box<dyn BufRead + 'v>) -> Records<'v> {
+    let mut failed = false;
+    Box::new(
+        serde_yaml::Deserializer::from_reader(reader)
+            .enumerate()
+            .map_while(move |(index, document)| {
+                if failed {
+                    return None;
+                }
+                Some(match Value::deserialize(document) {
+                    Ok(value) => validate(validator, index, None, &value),
+                    Err(e) => {
+                        // the parser can not recover from a syntax error
+                        failed = true;
+                        Record {
+                            index,
+                            line: e.location().map(|l| l.line()),
+                            result: Err(Failure::Parse(e.to_string())),
+                        }
+                    }
+                })
+            })
+            .map(Ok),
+    )
+}
+
+fn cell(field: Option<&FieldEnum>, cell: &str) -> Value {
+    let number = match field {
+        Some(FieldEnum::Integer(_)) => cell.parse::<i64>().ok().map(Number::from),
+        Some(FieldEnum::UInteger(_)) => cell.parse::<u64>().ok().map(Number::from),
+        Some(FieldEnum::Float(_)) => cell.parse::<f64>().ok().and_then(Number::from_f64),
+        Some(FieldEnum::Boolean(_)) => {
+            return match cell {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                _ => Value::String(cell.to_string()),
+            }
+        }
+        _ => None,
+    };
+    number
+        .map(Value::Number)
+        .unwrap_or_else(|| Value::String(cell.to_string()))
+}
+
+/// An empty cell is treated as a missing property.
+fn csv<'v>(validator: &'v Validator, reader: Box<dyn BufRead + 'v>) -> Result<Records<'v>> {
+    let properties = match validator.schema() {
+        FieldEnum::Object(o) => &o.properties,
+        _ => {
+            return Err(anyhow!(
+                "a CSV file can only be validated against an object schema"
+            ))
+        }
+    };
+    let mut reader = csv::Reader::from_reader(reader);
+    let headers = reader.headers()?.clone();
+    Ok(Box::new(reader.into_records().enumerate().map(
+        move |(index, row)| {
+            let row = row?;
+            let line = row.position().map(|p| p.line() as usize);
+            let object: Map<String, Value> = headers
+                .iter()
+                .zip(row.iter())
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(header, value)| {
+                    let field = properties.get(header).map(|f| f.as_ref());
+                    (header.to_string(), cell(field, value))
+                })
+                .collect();
+            Ok(validate(validator, index, line, &Value::Object(object)))
+        },
+    )))
+}
+
+pub fn records<'v>(
+    validator: &'v Validator,
+    format: Format,
+    reader: Box<dyn BufRead + 'v>,
+) -> Result<Records<'v>> {
+    match format {
+        Format::Json => json(validator, reader),
+        Format::Ndjson => Ok(Box::new(ndjson(validator, reader).map(streamed))),
+        Format::Yaml => Ok(yaml(validator, reader)),
+        Format::Csv => csv(validator, reader),
+    }
+}
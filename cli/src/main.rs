@@ -0,0 +1,170 @@
+mod input;
+
+use crate::input::{records, Failure, Format, Record};
+use anyhow::{anyhow, Context, Result};
+use avocado_schema::core::field::FieldEnum;
+use avocado_schema::core::parse::{from_value, ParseOptions};
+use avocado_schema::visitor::validator::Validator;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::{json, Map, Value};
+use std::fs::File;
+use std::io::{stdin, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "avocado", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Validates a data file against a schema
+    Validate {
+        /// The schema file, in JSON or YAML (by the .yaml/.yml extension)
+        #[arg(long, short)]
+        schema: PathBuf,
+        /// The format of the data, detected from the extension of the data file if not given
+        #[arg(long, short, value_enum)]
+        format: Option<Format>,
+        /// The format of the reported errors
+        #[arg(long, short, value_enum, default_value_t = Output::Human)]
+        output: Output,
+        /// The data file, or "-" to read from the standard input
+        data: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Output {
+    Human,
+    Json,
+}
+
+fn read_schema(path: &Path) -> Result<FieldEnum> {
+    let file = BufReader::new(
+        File::open(path).with_context(|| format!("failed to open {}", path.display()))?,
+    );
+    let document: Value = match Format::detect(path) {
+        Some(Format::Yaml) => serde_yaml::from_reader(file)?,
+        _ => serde_json::from_reader(file)?,
+    };
+    from_value(&document, &ParseOptions::new()).map_err(|errors| {
+        let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        anyhow!("invalid schema {}\n{}", path.display(), errors.join("\n"))
+    })
+}
+
+fn open(path: &Path) -> Result<Box<dyn BufRead>> {
+    if path == Path::new("-") {
+        return Ok(Box::new(stdin().lock()));
+    }
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn report_human(record: &Record) {
+    let Err(failure) = &record.result else {
+        return;
+    };
+    let location = match record.line {
+        Some(line) => format!("record {} (line {})", record.index, line),
+        None => format!("record {}", record.index),
+    };
+    match failure {
+        Failure::Parse(message) => println!("{}: {}", location, message),
+        Failure::Invalid(errors) => {
+            for (path, errors) in errors {
+                for error in errors {
+                    println!("{}: {}: {}", location, path, error);
+                }
+            }
+        }
+    }
+}
+
+fn report_json(record: &Record) -> Option<Value> {
+    let Err(failure) = &record.result else {
+        return None;
+    };
+    let mut report = Map::new();
+    report.insert("index".to_string(), json!(record.index));
+    report.insert("line".to_string(), json!(record.line));
+    match failure {
+        Failure::Parse(message) => {
+            report.insert("parse_error".to_string(), json!(message));
+        }
+        Failure::Invalid(errors) => {
+            let errors: Map<String, Value> = errors
+                .iter()
+                .map(|(path, errors)| {
+                    let messages = errors.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+                    (path.clone(), json!(messages))
+                })
+                .collect();
+            report.insert("errors".to_string(), Value::Object(errors));
+        }
+    }
+    Some(Value::Object(report))
+}
+
+fn validate(schema: &Path, format: Option<Format>, output: Output, data: &Path) -> Result<bool> {
+    let validator = Validator::new(read_schema(schema)?);
+    let format = format
+        .or_else(|| Format::detect(data))
+        .ok_or_else(|| anyhow!("can not detect the format of {}", data.display()))?;
+
+    let mut total = 0usize;
+    let mut invalid = 0usize;
+    for record in records(&validator, format, open(data)?)? {
+        let record = record?;
+        total += 1;
+        if record.result.is_err() {
+            invalid += 1;
+        }
+        match output {
+            Output::Human => report_human(&record),
+            Output::Json => {
+                if let Some(report) = report_json(&record) {
+                    println!("{}", report);
+                }
+            }
+        }
+    }
+
+    match output {
+        Output::Human if invalid == 0 => println!("{} record(s) are valid", total),
+        Output::Human => println!("{} of {} record(s) are invalid", invalid, total),
+        Output::Json => println!(
+            "{}",
+            json!({
+                "valid": invalid == 0,
+                "total": total,
+                "invalid": invalid,
+            })
+        ),
+    }
+    Ok(invalid == 0)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Validate {
+            schema,
+            format,
+            output,
+            data,
+        } => validate(schema, *format, *output, data),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn data(file: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/data")
+        .join(file)
+}
+
+fn validate(args: &[&str], file: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_avocado"))
+        .arg("validate")
+        .arg("--schema")
+        .arg(data("client.json"))
+        .args(args)
+        .arg(data(file))
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_valid() {
+    let output = validate(&[], "valid.json");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "1 record(s) are valid\n");
+}
+
+#[test]
+fn test_json_array() {
+    let output = validate(&[], "clients.json");
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = stdout(&output);
+    assert!(stdout.contains("record 1 (line 3): client/age: value 201 is larger then 200"));
+    assert!(stdout.contains("record 1 (line 3): client/name:"));
+    assert!(stdout.ends_with("1 of 2 record(s) are invalid\n"));
+}
+
+#[test]
+fn test_ndjson() {
+    let output = validate(&[], "clients.ndjson");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("record 1 (line 2): client/email:"));
+}
+
+#[test]
+fn test_yaml() {
+    let output = validate(&[], "clients.yaml");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("record 1: client: [name] field(s) are required"));
+}
+
+#[test]
+fn test_csv() {
+    let output = validate(&[], "clients.csv");
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = stdout(&output);
+    assert!(stdout.contains("record 1 (line 3): client/age:"));
+    assert!(!stdout.contains("client/email"));
+}
+
+#[test]
+fn test_json_output() {
+    let output = validate(&["--output", "json"], "clients.ndjson");
+    assert_eq!(output.status.code(), Some(1));
+    let lines = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["index"], 1);
+    assert_eq!(lines[0]["line"], 2);
+    assert_eq!(
+        lines[0]["errors"]["client/email"][0],
+        "value linda is not type email (Type)"
+    );
+    assert_eq!(
+        lines[1],
+        serde_json::json!({"valid": false, "total": 2, "invalid": 1})
+    );
+}
+
+#[test]
+fn test_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_avocado"))
+        .args(["validate", "--format", "ndjson", "--schema"])
+        .arg(data("client.json"))
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(br#"{"name": "Robert"}"#)
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_error() {
+    let output = validate(&[], "missing.json");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("failed to open"));
+}
+
+#[test]
+fn test_invalid_schema() {
+    let output = Command::new(env!("CARGO_BIN_EXE_avocado"))
+        .arg("validate")
+        .arg("--schema")
+        .arg(data("invalid_schema.json"))
+        .arg(data("valid.json"))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("/properties/name/maxLength: expected an unsigned integer, found -1"));
+}
@@ -0,0 +1,152 @@
+use avocado_schema::core::field::array::ArrayField;
+use avocado_schema::core::field::{Field, FieldEnum};
+use avocado_schema::core::value::{FieldValue, FieldValueRef, Reflect};
+use avocado_schema::visitor::validator::Validator;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+struct Client {
+    first_name: String,
+    last_name: String,
+    age: u64,
+    tags: Vec<String>,
+}
+
+impl Reflect for Client {
+    fn field_value(&self) -> FieldValue {
+        FieldValue::Object(BTreeMap::from([
+            ("first_name".to_string(), self.first_name.field_value()),
+            ("last_name".to_string(), self.last_name.field_value()),
+            ("age".to_string(), self.age.field_value()),
+            ("tags".to_string(), self.tags.field_value()),
+        ]))
+    }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Object(BTreeMap::from([
+            (
+                Cow::Borrowed("first_name"),
+                self.first_name.field_value_ref(),
+            ),
+            (Cow::Borrowed("last_name"), self.last_name.field_value_ref()),
+            (Cow::Borrowed("age"), self.age.field_value_ref()),
+            (Cow::Borrowed("tags"), self.tags.field_value_ref()),
+        ]))
+    }
+}
+
+const SCHEMA: &str = r#"
+{
+    "type": "array",
+    "name": "clients",
+    "item": {
+        "type": "object",
+        "name": "client",
+        "properties": {
+            "first_name": {
+                "type": "string",
+                "name": "first_name",
+                "enum": ["Robert", "Linda", "James", "Mary"],
+                "maxLength": 32,
+                "minLength": 1,
+                "pattern": "^[A-Z][a-z]+$"
+            },
+            "last_name": {
+                "type": "string",
+                "name": "last_name",
+                "maxLength": 32,
+                "minLength": 1
+            },
+            "age": {
+                "type": "uinteger",
+                "name": "age",
+                "maximum": 200,
+                "minimum": 0
+            },
+            "tags": {
+                "type": "array",
+                "name": "tags",
+                "item": {
+                    "type": "string",
+                    "name": "tag",
+                    "maxLength": 16
+                },
+                "unique": true
+            }
+        },
+        "required": ["first_name", "last_name", "age"]
+    }
+}"#;
+
+fn clients() -> Vec<Client> {
+    (0..10_000)
+        .map(|i| Client {
+            first_name: ["Robert", "Linda", "James", "Mary"][i % 4].to_string(),
+            last_name: "Li".to_string(),
+            age: (i % 100) as u64,
+            tags: vec!["vip".to_string(), "newsletter".to_string()],
+        })
+        .collect()
+}
+
+/// The validation before the schema was compiled, for comparison: the constraints are built
+/// on every visit and the value is cloned.
+fn validate_per_visit(field: &FieldEnum, value: FieldValue, errors: &mut Vec<String>) {
+    fn check(field: &impl Field, value: &FieldValue, errors: &mut Vec<String>) {
+        for constraint in field.constrains() {
+            if let Err(e) = constraint.validate(value) {
+                errors.push(e.to_string());
+            }
+        }
+    }
+
+    match field {
+        FieldEnum::Array(f) => {
+            check(f, &value, errors);
+            if let (FieldValue::Array(values), Some(item)) = (value.clone(), &f.item) {
+                for value in values {
+                    validate_per_visit(item, value, errors);
+                }
+            }
+        }
+        FieldEnum::Object(f) => {
+            check(f, &value, errors);
+            if let FieldValue::Object(o) = value.clone() {
+                for (name, value) in o {
+                    if let Some(property) = f.properties.get(name.as_str()) {
+                        validate_per_visit(property, value, errors);
+                    }
+                }
+            }
+        }
+        FieldEnum::String(f) => check(f, &value, errors),
+        FieldEnum::UInteger(f) => check(f, &value, errors),
+        _ => unimplemented!(),
+    }
+}
+
+fn bench_validate(c: &mut Criterion) {
+    let clients = clients();
+    let schema: FieldEnum = serde_json::from_str(SCHEMA).unwrap();
+    let validator = Validator::new(serde_json::from_str::<ArrayField>(SCHEMA).unwrap());
+
+    let mut group = c.benchmark_group("validate");
+    group.bench_function("per_visit", |b| {
+        b.iter(|| {
+            let mut errors = vec![];
+            validate_per_visit(&schema, black_box(&clients).field_value(), &mut errors);
+            assert!(errors.is_empty());
+        })
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| assert!(validator.validate(black_box(&clients)).is_ok()))
+    });
+    group.bench_function("is_valid", |b| {
+        b.iter(|| assert!(validator.is_valid(black_box(&clients))))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_validate);
+criterion_main!(benches);
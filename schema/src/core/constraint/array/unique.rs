@@ -1,5 +1,5 @@
 use crate::core::constraint::Constraint;
-use crate::core::value::FieldValue;
+use crate::core::value::FieldValueRef;
 use anyhow::anyhow;
 
 #[derive(Debug)]
@@ -8,13 +8,27 @@ pub struct Unique {
 }
 
 impl Constraint for Unique {
-    fn validate(&self, val: &FieldValue) -> anyhow::Result<()> {
+    fn name(&self) -> &'static str {
+        "Unique"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        match val {
+            FieldValueRef::Array(v) if self.unique => v
+                .iter()
+                .enumerate()
+                .all(|(i, value)| !v[..i].contains(value)),
+            _ => true,
+        }
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> anyhow::Result<()> {
         match val {
-            FieldValue::Array(v) if self.unique => {
-                let mut values: Vec<FieldValue> = vec![];
+            FieldValueRef::Array(v) if self.unique => {
+                let mut values: Vec<&FieldValueRef> = vec![];
                 for value in v {
-                    if !values.contains(value) {
-                        values.push(value.clone())
+                    if !values.contains(&value) {
+                        values.push(value)
                     } else {
                         return Err(anyhow!(format!(
                             "array contains duplicated item(s) ({})",
@@ -45,6 +59,7 @@ mod tests {
             FieldValue::Integer(3),
         ]);
         assert!(constraint.validate(&value).is_ok());
+        assert!(constraint.is_valid(&(&value).into()));
 
         let value = FieldValue::Array(vec![
             FieldValue::Integer(1),
@@ -52,5 +67,6 @@ mod tests {
             FieldValue::Integer(2),
         ]);
         assert!(constraint.validate(&value).is_err());
+        assert!(!constraint.is_valid(&(&value).into()));
     }
 }
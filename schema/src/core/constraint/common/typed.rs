@@ -1,6 +1,6 @@
 use crate::core::constraint::Constraint;
 use crate::core::field::FieldType;
-use crate::core::value::FieldValue;
+use crate::core::value::FieldValueRef;
 use anyhow::{anyhow, Result};
 
 #[derive(Debug)]
@@ -9,23 +9,35 @@ pub struct Type {
 }
 
 impl Constraint for Type {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
-        match val {
-            FieldValue::Boolean(_) if matches!(self.typed, FieldType::Boolean) => Ok(()),
-            FieldValue::Integer(_) if matches!(self.typed, FieldType::Integer) => Ok(()),
-            FieldValue::UInteger(_) if matches!(self.typed, FieldType::UInteger) => Ok(()),
-            FieldValue::Float(_) if matches!(self.typed, FieldType::Float) => Ok(()),
-            FieldValue::Email(_) if matches!(self.typed, FieldType::Email) => Ok(()),
-            FieldValue::DateTime(_) if matches!(self.typed, FieldType::DateTime) => Ok(()),
-            FieldValue::Date(_) if matches!(self.typed, FieldType::Date) => Ok(()),
-            FieldValue::Time(_) if matches!(self.typed, FieldType::Time) => Ok(()),
-            FieldValue::String(_) if matches!(self.typed, FieldType::String) => Ok(()),
-            FieldValue::Array(_) if matches!(self.typed, FieldType::Array) => Ok(()),
-            FieldValue::Object(_) if matches!(self.typed, FieldType::Object) => Ok(()),
-            _ => Err(anyhow!(format!(
+    fn name(&self) -> &'static str {
+        "Type"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        matches!(
+            (val, &self.typed),
+            (FieldValueRef::Boolean(_), FieldType::Boolean)
+                | (FieldValueRef::Integer(_), FieldType::Integer)
+                | (FieldValueRef::UInteger(_), FieldType::UInteger)
+                | (FieldValueRef::Float(_), FieldType::Float)
+                | (FieldValueRef::Email(_), FieldType::Email)
+                | (FieldValueRef::DateTime(_), FieldType::DateTime)
+                | (FieldValueRef::Date(_), FieldType::Date)
+                | (FieldValueRef::Time(_), FieldType::Time)
+                | (FieldValueRef::String(_), FieldType::String)
+                | (FieldValueRef::Array(_), FieldType::Array)
+                | (FieldValueRef::Object(_), FieldType::Object)
+        )
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
+        if self.is_valid(val) {
+            Ok(())
+        } else {
+            Err(anyhow!(format!(
                 "value {} is not type {} ({})",
                 val, self.typed, "Type"
-            ))),
+            )))
         }
     }
 }
@@ -45,8 +57,10 @@ mod tests {
 
         let value = FieldValue::Boolean(true);
         assert!(constraint.validate(&value).is_ok());
+        assert!(constraint.is_valid(&(&value).into()));
 
         let value = FieldValue::String("Test".to_string());
-        assert!(constraint.validate(&value).is_err())
+        assert!(constraint.validate(&value).is_err());
+        assert!(!constraint.is_valid(&(&value).into()));
     }
 }
@@ -1,8 +1,17 @@
-use crate::core::value::FieldValue;
+use crate::core::value::{FieldValue, FieldValueRef};
 use anyhow::Result;
+use std::fmt::Debug;
 
-pub trait Constraint {
-    fn validate(&self, val: &FieldValue) -> Result<()>;
+pub trait Constraint: Debug + Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool;
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()>;
+
+    fn validate(&self, val: &FieldValue) -> Result<()> {
+        self.validate_ref(&val.into())
+    }
 }
 
 pub mod array;
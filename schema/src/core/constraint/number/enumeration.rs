@@ -1,5 +1,5 @@
 use crate::core::constraint::Constraint;
-use crate::core::value::FieldValue;
+use crate::core::value::FieldValueRef;
 use anyhow::{anyhow, Result};
 
 #[derive(Clone, Debug)]
@@ -8,9 +8,17 @@ pub struct Enumeration<T: Copy> {
 }
 
 impl Constraint for Enumeration<i64> {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "Enum"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::Integer(v) if !self.values.contains(v))
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::Integer(v) if !self.values.contains(v) => Err(anyhow!(format!(
+            FieldValueRef::Integer(v) if !self.values.contains(v) => Err(anyhow!(format!(
                 "value {} is not valid value ({})",
                 v, "Enum"
             ))),
@@ -20,9 +28,17 @@ impl Constraint for Enumeration<i64> {
 }
 
 impl Constraint for Enumeration<u64> {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "Enum"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::UInteger(v) if !self.values.contains(v))
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::UInteger(v) if !self.values.contains(v) => Err(anyhow!(format!(
+            FieldValueRef::UInteger(v) if !self.values.contains(v) => Err(anyhow!(format!(
                 "value {} is not valid value ({})",
                 v, "Enum"
             ))),
@@ -32,9 +48,17 @@ impl Constraint for Enumeration<u64> {
 }
 
 impl Constraint for Enumeration<f64> {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "Enum"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::Float(v) if !self.values.contains(v))
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::Float(v) if !self.values.contains(v) => Err(anyhow!(format!(
+            FieldValueRef::Float(v) if !self.values.contains(v) => Err(anyhow!(format!(
                 "value {} is not valid value ({})",
                 v, "Enum"
             ))),
@@ -1,5 +1,5 @@
 use crate::core::constraint::Constraint;
-use crate::core::value::FieldValue;
+use crate::core::value::FieldValueRef;
 use anyhow::{anyhow, Result};
 
 #[derive(Debug)]
@@ -8,9 +8,17 @@ pub struct ExclusiveMinimum<T: Copy> {
 }
 
 impl Constraint for ExclusiveMinimum<i64> {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "ExclusiveMinimum"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::Integer(v) if *v <= self.min_val)
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::Integer(v) if (*v <= self.min_val) => Err(anyhow!(format!(
+            FieldValueRef::Integer(v) if (*v <= self.min_val) => Err(anyhow!(format!(
                 "value {} is less then or equals to {} ({})",
                 v, self.min_val, "ExclusiveMinimum"
             ))),
@@ -20,9 +28,17 @@ impl Constraint for ExclusiveMinimum<i64> {
 }
 
 impl Constraint for ExclusiveMinimum<u64> {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "ExclusiveMinimum"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::UInteger(v) if *v <= self.min_val)
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::UInteger(v) if (*v <= self.min_val) => Err(anyhow!(format!(
+            FieldValueRef::UInteger(v) if (*v <= self.min_val) => Err(anyhow!(format!(
                 "value {} is less then or equals to {} ({})",
                 v, self.min_val, "ExclusiveMinimum"
             ))),
@@ -32,9 +48,17 @@ impl Constraint for ExclusiveMinimum<u64> {
 }
 
 impl Constraint for ExclusiveMinimum<f64> {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "ExclusiveMinimum"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::Float(v) if *v <= self.min_val)
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::Float(v) if (*v <= self.min_val) => Err(anyhow!(format!(
+            FieldValueRef::Float(v) if (*v <= self.min_val) => Err(anyhow!(format!(
                 "value {} is less then or equals to {} ({})",
                 v, self.min_val, "ExclusiveMinimum"
             ))),
@@ -1,5 +1,5 @@
 use crate::core::constraint::Constraint;
-use crate::core::value::FieldValue;
+use crate::core::value::FieldValueRef;
 use anyhow::{anyhow, Result};
 
 #[derive(Clone, Debug)]
@@ -8,16 +8,30 @@ pub struct Required {
 }
 
 impl Constraint for Required {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "Required"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        match val {
+            FieldValueRef::Object(o) => self
+                .required
+                .iter()
+                .all(|field| !matches!(o.get(field.as_str()), None | Some(FieldValueRef::Null))),
+            _ => true,
+        }
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::Object(o) => {
+            FieldValueRef::Object(o) => {
                 let mut missing_fields = vec![];
                 for field in &self.required {
                     match o.get(field.as_str()) {
                         None => {
                             missing_fields.push(field.clone());
                         }
-                        Some(&FieldValue::Null) => {
+                        Some(&FieldValueRef::Null) => {
                             missing_fields.push(field.clone());
                         }
                         _ => {}
@@ -69,10 +83,12 @@ mod test {
             required: vec!["title".to_string()],
         };
         assert!(constraint.validate(&document.field_value()).is_ok());
+        assert!(constraint.is_valid(&document.field_value_ref()));
 
         let constraint = Required {
             required: vec!["title".to_string(), "body".to_string()],
         };
         assert!(constraint.validate(&document.field_value()).is_err());
+        assert!(!constraint.is_valid(&document.field_value_ref()));
     }
 }
@@ -1,5 +1,5 @@
 use crate::core::constraint::Constraint;
-use crate::core::value::FieldValue;
+use crate::core::value::FieldValueRef;
 use anyhow::{anyhow, Result};
 
 #[derive(Clone, Debug)]
@@ -8,12 +8,19 @@ pub struct Enumeration {
 }
 
 impl Constraint for Enumeration {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "Enum"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::String(v) if !self.values.iter().any(|s| s == v))
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::String(v) if !self.values.contains(v) => Err(anyhow!(format!(
-                "value {} is not valid value ({})",
-                v, "Enum of String"
-            ))),
+            FieldValueRef::String(v) if !self.values.iter().any(|s| s == v) => Err(anyhow!(
+                format!("value {} is not valid value ({})", v, "Enum of String")
+            )),
             _ => Ok(()),
         }
     }
@@ -1,5 +1,5 @@
 use crate::core::constraint::Constraint;
-use crate::core::value::FieldValue;
+use crate::core::value::FieldValueRef;
 use anyhow::{anyhow, Result};
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -9,9 +9,17 @@ pub struct MaxLength {
 }
 
 impl Constraint for MaxLength {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "MaxLength"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::String(v) if v.graphemes(true).count() > self.max_length)
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::String(v) if v.graphemes(true).count() > self.max_length => {
+            FieldValueRef::String(v) if v.graphemes(true).count() > self.max_length => {
                 Err(anyhow!(format!(
                     "length of {} is larger then {} ({})",
                     v,
@@ -1,5 +1,5 @@
 use crate::core::constraint::Constraint;
-use crate::core::value::FieldValue;
+use crate::core::value::FieldValueRef;
 use anyhow::{anyhow, Result};
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -9,9 +9,17 @@ pub struct MinLength {
 }
 
 impl Constraint for MinLength {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "MinLength"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::String(v) if v.graphemes(true).count() < self.min_length)
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::String(v) if v.graphemes(true).count() < self.min_length => {
+            FieldValueRef::String(v) if v.graphemes(true).count() < self.min_length => {
                 Err(anyhow!(format!(
                     "length of {} is less then {} ({})",
                     v, self.min_length, "MinLength"
@@ -1,5 +1,5 @@
 use crate::core::constraint::Constraint;
-use crate::core::value::FieldValue;
+use crate::core::value::FieldValueRef;
 use anyhow::{anyhow, Result};
 use regex::Regex;
 use serde::de::{Error, Visitor};
@@ -51,9 +51,17 @@ impl<'de> Visitor<'de> for PatternVisitor {
 }
 
 impl Constraint for Pattern {
-    fn validate(&self, val: &FieldValue) -> Result<()> {
+    fn name(&self) -> &'static str {
+        "Pattern"
+    }
+
+    fn is_valid(&self, val: &FieldValueRef) -> bool {
+        !matches!(val, FieldValueRef::String(v) if !self.pattern.is_match(v))
+    }
+
+    fn validate_ref(&self, val: &FieldValueRef) -> Result<()> {
         match val {
-            FieldValue::String(v) if !self.pattern.is_match(v.as_str()) => Err(anyhow!(format!(
+            FieldValueRef::String(v) if !self.pattern.is_match(v) => Err(anyhow!(format!(
                 "{} does not match pattern {} ({})",
                 self.pattern, v, "Pattern"
             ))),
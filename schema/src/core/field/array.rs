@@ -1,6 +1,7 @@
 use crate::core::constraint::array::unique::Unique;
 use crate::core::constraint::common::typed::Type;
 use crate::core::constraint::Constraint;
+use crate::core::field::metadata::{metadata_setters, Metadata};
 use crate::core::field::FieldEnum;
 use crate::core::field::{Field, FieldType};
 use serde::{Deserialize, Serialize};
@@ -9,7 +10,11 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "type", rename = "array")]
 pub struct ArrayField {
     pub name: String,
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item: Option<Box<FieldEnum>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unique: Option<bool>,
 }
 
@@ -20,6 +25,10 @@ impl Field for ArrayField {
         self.name.clone()
     }
 
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     fn constrains(&self) -> Vec<Box<dyn Constraint>> {
         let mut constraints: Vec<Box<dyn Constraint>> = vec![Box::new(Type {
             typed: Self::FIELD_TYPE,
@@ -34,6 +43,7 @@ impl Field for ArrayField {
 #[derive(Default)]
 pub struct ArrayFieldBuilder {
     name: String,
+    metadata: Metadata,
     item: Option<FieldEnum>,
     unique: Option<bool>,
 }
@@ -48,6 +58,8 @@ impl ArrayFieldBuilder {
         self
     }
 
+    metadata_setters!();
+
     pub fn item(mut self, item: impl Field) -> Self {
         self.item = Some(item.into());
         self
@@ -61,6 +73,7 @@ impl ArrayFieldBuilder {
     pub fn build(self) -> ArrayField {
         ArrayField {
             name: self.name,
+            metadata: self.metadata,
             item: self.item.map(Box::new),
             unique: self.unique,
         }
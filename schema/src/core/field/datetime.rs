@@ -1,5 +1,6 @@
 use crate::core::constraint::common::typed::Type;
 use crate::core::constraint::Constraint;
+use crate::core::field::metadata::{metadata_setters, Metadata};
 use crate::core::field::{Field, FieldType};
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +8,8 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "type", rename = "datetime")]
 pub struct DatetimeField {
     pub name: String,
+    #[serde(flatten)]
+    pub metadata: Metadata,
 }
 
 impl Field for DatetimeField {
@@ -16,6 +19,10 @@ impl Field for DatetimeField {
         self.name.clone()
     }
 
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     fn constrains(&self) -> Vec<Box<dyn Constraint>> {
         vec![Box::new(Type {
             typed: Self::FIELD_TYPE,
@@ -26,6 +33,7 @@ impl Field for DatetimeField {
 #[derive(Default)]
 pub struct DatetimeFieldBuilder {
     name: String,
+    metadata: Metadata,
 }
 
 impl DatetimeFieldBuilder {
@@ -38,8 +46,13 @@ impl DatetimeFieldBuilder {
         self
     }
 
+    metadata_setters!();
+
     pub fn build(self) -> DatetimeField {
-        DatetimeField { name: self.name }
+        DatetimeField {
+            name: self.name,
+            metadata: self.metadata,
+        }
     }
 }
 
@@ -5,6 +5,7 @@ use crate::core::constraint::number::exclusive_minimum::ExclusiveMinimum;
 use crate::core::constraint::number::maximum::Maximum;
 use crate::core::constraint::number::minimum::Minimum;
 use crate::core::constraint::Constraint;
+use crate::core::field::metadata::{metadata_setters, Metadata};
 use crate::core::field::{Field, FieldType};
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +13,8 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "type", rename = "float")]
 pub struct FloatField {
     pub name: String,
+    #[serde(flatten)]
+    pub metadata: Metadata,
     #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
     pub enumeration: Option<Vec<f64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -31,6 +34,10 @@ impl Field for FloatField {
         self.name.clone()
     }
 
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     fn constrains(&self) -> Vec<Box<dyn Constraint>> {
         let mut constraints: Vec<Box<dyn Constraint>> = vec![Box::new(Type {
             typed: Self::FIELD_TYPE,
@@ -57,6 +64,7 @@ impl Field for FloatField {
 #[derive(Default)]
 pub struct FloatFieldBuilder {
     name: String,
+    metadata: Metadata,
     enumeration: Option<Vec<f64>>,
     maximum: Option<f64>,
     exclusive_maximum: Option<f64>,
@@ -74,6 +82,8 @@ impl FloatFieldBuilder {
         self
     }
 
+    metadata_setters!();
+
     pub fn enumeration(mut self, numbers: Vec<f64>) -> Self {
         self.enumeration = Some(numbers);
         self
@@ -102,6 +112,7 @@ impl FloatFieldBuilder {
     pub fn build(self) -> FloatField {
         FloatField {
             name: self.name,
+            metadata: self.metadata,
             enumeration: self.enumeration,
             maximum: self.maximum,
             exclusive_maximum: self.exclusive_maximum,
@@ -0,0 +1,188 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// The annotations shared by all the types of field, which do not affect validation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<bool>,
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(rename = "writeOnly", skip_serializing_if = "Option::is_none")]
+    pub write_only: Option<bool>,
+    /// The custom keywords prefixed by `x-`, e.g. `x-widget`, the other unknown keywords are dropped.
+    #[serde(flatten, deserialize_with = "extensions")]
+    pub extensions: BTreeMap<String, Value>,
+}
+
+impl Metadata {
+    pub fn extension(&self, name: &str) -> Option<&Value> {
+        self.extensions.get(name)
+    }
+}
+
+fn extensions<'de, D>(deserializer: D) -> Result<BTreeMap<String, Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let keywords = BTreeMap::<String, Value>::deserialize(deserializer)?;
+    Ok(keywords
+        .into_iter()
+        .filter(|(k, _)| k.starts_with("x-"))
+        .collect())
+}
+
+macro_rules! metadata_setters {
+    () => {
+        pub fn title(mut self, title: &'static str) -> Self {
+            self.metadata.title = Some(title.to_string());
+            self
+        }
+
+        pub fn description(mut self, description: &'static str) -> Self {
+            self.metadata.description = Some(description.to_string());
+            self
+        }
+
+        pub fn examples(mut self, examples: Vec<serde_json::Value>) -> Self {
+            self.metadata.examples = Some(examples);
+            self
+        }
+
+        pub fn default_value(mut self, default: impl Into<serde_json::Value>) -> Self {
+            self.metadata.default = Some(default.into());
+            self
+        }
+
+        pub fn deprecated(mut self, deprecated: bool) -> Self {
+            self.metadata.deprecated = Some(deprecated);
+            self
+        }
+
+        pub fn read_only(mut self, read_only: bool) -> Self {
+            self.metadata.read_only = Some(read_only);
+            self
+        }
+
+        pub fn write_only(mut self, write_only: bool) -> Self {
+            self.metadata.write_only = Some(write_only);
+            self
+        }
+
+        /// # Panics
+        ///
+        /// Panics if the name is not prefixed by `x-`.
+        pub fn extension(
+            mut self,
+            name: &'static str,
+            value: impl Into<serde_json::Value>,
+        ) -> Self {
+            assert!(
+                name.starts_with("x-"),
+                "extension {} is not prefixed by x-",
+                name
+            );
+            self.metadata
+                .extensions
+                .insert(name.to_string(), value.into());
+            self
+        }
+    };
+}
+
+pub(crate) use metadata_setters;
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::object::ObjectField;
+    use crate::core::field::string::{StringField, StringFieldBuilder};
+    use crate::core::field::uinteger::UIntegerFieldBuilder;
+    use crate::core::field::Field;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize() {
+        let field = StringFieldBuilder::new()
+            .name("nickname")
+            .title("Nickname")
+            .description("The name used by friends")
+            .examples(vec![json!("Bob")])
+            .default_value("Anonymous")
+            .deprecated(true)
+            .read_only(false)
+            .write_only(false)
+            .max_length(32)
+            .build();
+        let field_json = serde_json::to_string(&field).unwrap();
+        assert_eq!(
+            field_json,
+            r#"{"type":"string","name":"nickname","title":"Nickname","description":"The name used by friends","examples":["Bob"],"default":"Anonymous","deprecated":true,"readOnly":false,"writeOnly":false,"maxLength":32}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let field_json = r#"
+        {
+            "type": "string",
+            "name": "nickname",
+            "title": "Nickname",
+            "default": "Anonymous",
+            "readOnly": true,
+            "maxLength": 32
+        }"#;
+        let field: StringField = serde_json::from_str(field_json).unwrap();
+        let metadata = field.metadata();
+        assert_eq!(metadata.title, Some("Nickname".to_string()));
+        assert_eq!(metadata.default, Some(json!("Anonymous")));
+        assert_eq!(metadata.read_only, Some(true));
+        assert_eq!(metadata.description, None);
+        assert_eq!(field.max_length, Some(32));
+    }
+
+    #[test]
+    fn test_extensions() {
+        let field_json = r#"{"type":"object","name":"client","x-table":"clients","properties":{"age":{"type":"uinteger","name":"age","x-widget":{"type":"slider","step":1},"x-indexed":true,"placeholder":"dropped","maximum":200}}}"#;
+        let field: ObjectField = serde_json::from_str(field_json).unwrap();
+        assert_eq!(
+            field.metadata().extension("x-table"),
+            Some(&json!("clients"))
+        );
+        let age = field.properties["age"].metadata();
+        assert_eq!(
+            age.extension("x-widget"),
+            Some(&json!({"type": "slider", "step": 1}))
+        );
+        assert_eq!(age.extension("x-indexed"), Some(&json!(true)));
+        assert_eq!(age.extension("placeholder"), None);
+
+        let round_trip = serde_json::to_value(&field).unwrap();
+        let expected: serde_json::Value =
+            serde_json::from_str(&field_json.replace(r#""placeholder":"dropped","#, "")).unwrap();
+        assert_eq!(round_trip, expected);
+
+        let field = UIntegerFieldBuilder::new()
+            .name("age")
+            .extension("x-indexed", true)
+            .build();
+        assert_eq!(
+            serde_json::to_string(&field).unwrap(),
+            r#"{"type":"uinteger","name":"age","x-indexed":true}"#
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_extension_prefix() {
+        UIntegerFieldBuilder::new().extension("indexed", true);
+    }
+}
@@ -6,7 +6,9 @@ use crate::core::field::datetime::DatetimeField;
 use crate::core::field::email::EmailField;
 use crate::core::field::float::FloatField;
 use crate::core::field::integer::IntegerField;
+use crate::core::field::metadata::Metadata;
 use crate::core::field::object::ObjectField;
+use crate::core::field::reference::ReferenceField;
 use crate::core::field::string::StringField;
 use crate::core::field::time::TimeField;
 use crate::core::field::uinteger::UIntegerField;
@@ -14,7 +16,7 @@ use serde::{Deserialize, Serialize, Serializer};
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FieldType {
     String,
     Integer,
@@ -27,6 +29,7 @@ pub enum FieldType {
     DateTime,
     Date,
     Time,
+    Reference,
 }
 
 impl fmt::Display for FieldType {
@@ -43,14 +46,31 @@ impl fmt::Display for FieldType {
             FieldType::DateTime => write!(f, "datetime"),
             FieldType::Date => write!(f, "date"),
             FieldType::Time => write!(f, "time"),
+            FieldType::Reference => write!(f, "reference"),
         }
     }
 }
 
+pub(crate) const FIELD_TYPES: [&str; 12] = [
+    "array",
+    "boolean",
+    "float",
+    "integer",
+    "uinteger",
+    "object",
+    "string",
+    "email",
+    "datetime",
+    "date",
+    "time",
+    "reference",
+];
+
 pub trait Field: Debug + Into<FieldEnum> {
     const FIELD_TYPE: FieldType;
 
     fn name(&self) -> String;
+    fn metadata(&self) -> &Metadata;
     fn constrains(&self) -> Vec<Box<dyn Constraint>>;
 }
 
@@ -61,7 +81,9 @@ pub mod datetime;
 pub mod email;
 pub mod float;
 pub mod integer;
+pub mod metadata;
 pub mod object;
+pub mod reference;
 pub mod string;
 pub mod time;
 pub mod uinteger;
@@ -85,6 +107,32 @@ macro_rules! field_enum {
             }
         }
 
+        impl FieldEnum {
+            pub fn name(&self) -> String {
+                match &self {
+                    $(FieldEnum::$field_name(f) => f.name(),)*
+                }
+            }
+
+            pub fn metadata(&self) -> &Metadata {
+                match &self {
+                    $(FieldEnum::$field_name(f) => f.metadata(),)*
+                }
+            }
+
+            pub fn field_type(&self) -> FieldType {
+                match &self {
+                    $(FieldEnum::$field_name(_) => $field::FIELD_TYPE,)*
+                }
+            }
+
+            pub fn constrains(&self) -> Vec<Box<dyn Constraint>> {
+                match &self {
+                    $(FieldEnum::$field_name(f) => f.constrains(),)*
+                }
+            }
+        }
+
         $(
         impl From<$field> for FieldEnum {
             fn from(value: $field) -> Self {
@@ -106,5 +154,6 @@ field_enum!(
     Email(EmailField),
     Datetime(DatetimeField),
     Date(DateField),
-    Time(TimeField)
+    Time(TimeField),
+    Reference(ReferenceField)
 );
@@ -1,6 +1,7 @@
 use crate::core::constraint::common::typed::Type;
 use crate::core::constraint::object::required::Required;
 use crate::core::constraint::Constraint;
+use crate::core::field::metadata::{metadata_setters, Metadata};
 use crate::core::field::FieldEnum;
 use crate::core::field::{Field, FieldType};
 use serde::{Deserialize, Serialize};
@@ -10,9 +11,17 @@ use std::collections::BTreeMap;
 #[serde(tag = "type", rename = "object")]
 pub struct ObjectField {
     pub name: String,
+    #[serde(flatten)]
+    pub metadata: Metadata,
     pub properties: BTreeMap<String, Box<FieldEnum>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<Vec<String>>,
+    /// The field of the values whose keys are not in the properties.
+    #[serde(
+        rename = "additionalProperties",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub additional_properties: Option<Box<FieldEnum>>,
 }
 
 impl Field for ObjectField {
@@ -22,6 +31,10 @@ impl Field for ObjectField {
         self.name.clone()
     }
 
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     fn constrains(&self) -> Vec<Box<dyn Constraint>> {
         let mut constraints: Vec<Box<dyn Constraint>> = vec![Box::new(Type {
             typed: Self::FIELD_TYPE,
@@ -38,8 +51,10 @@ impl Field for ObjectField {
 #[derive(Default)]
 pub struct ObjectFieldBuilder {
     name: String,
+    metadata: Metadata,
     properties: BTreeMap<String, Box<FieldEnum>>,
     required: Option<Vec<String>>,
+    additional_properties: Option<Box<FieldEnum>>,
 }
 
 impl ObjectFieldBuilder {
@@ -52,6 +67,8 @@ impl ObjectFieldBuilder {
         self
     }
 
+    metadata_setters!();
+
     pub fn property(mut self, name: &'static str, field: impl Field) -> Self {
         self.properties
             .insert(name.to_string(), Box::new(field.into()));
@@ -63,11 +80,18 @@ impl ObjectFieldBuilder {
         self
     }
 
+    pub fn additional_properties(mut self, field: impl Field) -> Self {
+        self.additional_properties = Some(Box::new(field.into()));
+        self
+    }
+
     pub fn build(self) -> ObjectField {
         ObjectField {
             name: self.name,
+            metadata: self.metadata,
             properties: self.properties,
             required: self.required,
+            additional_properties: self.additional_properties,
         }
     }
 }
@@ -233,4 +257,40 @@ mod tests {
             .is_ok());
         assert!(validator.validate(&Client { name: None }).is_err());
     }
+
+    #[test]
+    fn test_additional_properties() {
+        let field = ObjectFieldBuilder::new()
+            .name("scores")
+            .property("total", IntegerFieldBuilder::new().name("total").build())
+            .additional_properties(
+                IntegerFieldBuilder::new()
+                    .name("score")
+                    .maximum(100)
+                    .build(),
+            )
+            .build();
+        let field_json = serde_json::to_string(&field).unwrap();
+        assert_eq!(
+            field_json,
+            r#"{"type":"object","name":"scores","properties":{"total":{"type":"integer","name":"total"}},"additionalProperties":{"type":"integer","name":"score","maximum":100}}"#
+        );
+        let field: ObjectField = serde_json::from_str(&field_json).unwrap();
+        assert!(field.additional_properties.is_some());
+
+        let validator = Validator::new(field);
+        let scores = FieldValue::Object(BTreeMap::from([
+            ("total".to_string(), FieldValue::Integer(120)),
+            ("math".to_string(), FieldValue::Integer(90)),
+            ("english".to_string(), FieldValue::Integer(101)),
+        ]));
+        assert!(validator.is_valid(&FieldValue::Object(BTreeMap::from([(
+            "math".to_string(),
+            FieldValue::Integer(90)
+        )]))));
+        assert!(!validator.is_valid(&scores));
+        let errors = validator.validate(&scores).err().unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors.contains_key("scores/english"));
+    }
 }
@@ -0,0 +1,186 @@
+use crate::core::constraint::Constraint;
+use crate::core::field::metadata::{metadata_setters, Metadata};
+use crate::core::field::{Field, FieldType};
+use serde::{Deserialize, Serialize};
+
+/// A field which refers to one of its enclosing fields by name, for recursive data.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "reference")]
+pub struct ReferenceField {
+    pub name: String,
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    pub reference: String,
+}
+
+impl Field for ReferenceField {
+    const FIELD_TYPE: FieldType = FieldType::Reference;
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    fn constrains(&self) -> Vec<Box<dyn Constraint>> {
+        vec![]
+    }
+}
+
+#[derive(Default)]
+pub struct ReferenceFieldBuilder {
+    name: String,
+    metadata: Metadata,
+    reference: String,
+}
+
+impl ReferenceFieldBuilder {
+    pub fn new() -> Self {
+        ReferenceFieldBuilder::default()
+    }
+
+    pub fn name(mut self, name: &'static str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    metadata_setters!();
+
+    pub fn reference(mut self, reference: &'static str) -> Self {
+        self.reference = reference.to_string();
+        self
+    }
+
+    pub fn build(self) -> ReferenceField {
+        ReferenceField {
+            name: self.name,
+            metadata: self.metadata,
+            reference: self.reference,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::array::ArrayFieldBuilder;
+    use crate::core::field::object::{ObjectField, ObjectFieldBuilder};
+    use crate::core::field::reference::{ReferenceField, ReferenceFieldBuilder};
+    use crate::core::field::string::StringFieldBuilder;
+    use crate::core::value::{FieldValue, Reflect};
+    use crate::visitor::validator::Validator;
+    use std::collections::BTreeMap;
+
+    struct Category {
+        name: String,
+        children: Vec<Category>,
+    }
+
+    impl Reflect for Category {
+        fn field_value(&self) -> FieldValue {
+            FieldValue::Object(BTreeMap::from([
+                ("name".to_string(), self.name.field_value()),
+                ("children".to_string(), self.children.field_value()),
+            ]))
+        }
+    }
+
+    fn category_schema() -> ObjectField {
+        ObjectFieldBuilder::new()
+            .name("category")
+            .property(
+                "name",
+                StringFieldBuilder::new().name("name").min_length(1).build(),
+            )
+            .property(
+                "children",
+                ArrayFieldBuilder::new()
+                    .name("children")
+                    .item(
+                        ReferenceFieldBuilder::new()
+                            .name("child")
+                            .reference("category")
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_serialize() {
+        let field = ReferenceFieldBuilder::new()
+            .name("child")
+            .reference("category")
+            .build();
+        let field_json = serde_json::to_string(&field).unwrap();
+        assert_eq!(
+            field_json,
+            r#"{"type":"reference","name":"child","reference":"category"}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let field_json = r#"
+        {
+            "type":"reference",
+            "name": "child",
+            "reference": "category"
+        }"#;
+        let field: ReferenceField = serde_json::from_str(field_json).unwrap();
+        assert_eq!(field.name, "child");
+        assert_eq!(field.reference, "category");
+    }
+
+    #[test]
+    fn test_recursion() {
+        let validator = Validator::new(category_schema());
+
+        let valid_category = Category {
+            name: "Books".to_string(),
+            children: vec![Category {
+                name: "Fiction".to_string(),
+                children: vec![Category {
+                    name: "Fantasy".to_string(),
+                    children: vec![],
+                }],
+            }],
+        };
+        assert!(validator.validate(&valid_category).is_ok());
+
+        let invalid_category = Category {
+            name: "Books".to_string(),
+            children: vec![Category {
+                name: "Fiction".to_string(),
+                children: vec![Category {
+                    name: "".to_string(),
+                    children: vec![],
+                }],
+            }],
+        };
+        let result = validator.validate(&invalid_category);
+        assert!(result
+            .err()
+            .unwrap()
+            .contains_key("category/children/child/children/child/name"));
+    }
+
+    #[test]
+    fn test_unresolved_reference() {
+        let field = ArrayFieldBuilder::new()
+            .name("children")
+            .item(
+                ReferenceFieldBuilder::new()
+                    .name("child")
+                    .reference("category")
+                    .build(),
+            )
+            .build();
+        let validator = Validator::new(field);
+
+        assert!(validator.validate(&Vec::<String>::new()).is_ok());
+        assert!(validator.validate(&vec!["Books"]).is_err());
+    }
+}
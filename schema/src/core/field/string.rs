@@ -4,7 +4,9 @@ use crate::core::constraint::string::max_length::MaxLength;
 use crate::core::constraint::string::min_length::MinLength;
 use crate::core::constraint::string::pattern::Pattern;
 use crate::core::constraint::Constraint;
+use crate::core::field::metadata::{metadata_setters, Metadata};
 use crate::core::field::{Field, FieldType};
+use crate::core::transform::Transform;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +14,8 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "type", rename = "string")]
 pub struct StringField {
     pub name: String,
+    #[serde(flatten)]
+    pub metadata: Metadata,
     #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
     pub enumeration: Option<Vec<String>>,
     #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
@@ -20,6 +24,8 @@ pub struct StringField {
     pub min_length: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pattern: Option<Pattern>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transforms: Option<Vec<Transform>>,
 }
 
 impl Field for StringField {
@@ -29,6 +35,10 @@ impl Field for StringField {
         self.name.clone()
     }
 
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     fn constrains(&self) -> Vec<Box<dyn Constraint>> {
         let mut constraints: Vec<Box<dyn Constraint>> = vec![Box::new(Type {
             typed: Self::FIELD_TYPE,
@@ -52,10 +62,12 @@ impl Field for StringField {
 #[derive(Default)]
 pub struct StringFieldBuilder {
     name: String,
+    metadata: Metadata,
     enumeration: Option<Vec<String>>,
     max_length: Option<usize>,
     min_length: Option<usize>,
     pattern: Option<Regex>,
+    transforms: Option<Vec<Transform>>,
 }
 
 impl StringFieldBuilder {
@@ -68,6 +80,8 @@ impl StringFieldBuilder {
         self
     }
 
+    metadata_setters!();
+
     pub fn enumeration(mut self, strings: Vec<String>) -> Self {
         self.enumeration = Some(strings);
         self
@@ -88,13 +102,20 @@ impl StringFieldBuilder {
         self
     }
 
+    pub fn transforms(mut self, transforms: Vec<Transform>) -> Self {
+        self.transforms = Some(transforms);
+        self
+    }
+
     pub fn build(self) -> StringField {
         StringField {
             name: self.name,
+            metadata: self.metadata,
             enumeration: self.enumeration,
             max_length: self.max_length,
             min_length: self.min_length,
             pattern: self.pattern.map(|pattern| Pattern { pattern }),
+            transforms: self.transforms,
         }
     }
 }
@@ -102,6 +123,7 @@ impl StringFieldBuilder {
 #[cfg(test)]
 mod tests {
     use crate::core::field::string::{StringField, StringFieldBuilder};
+    use crate::core::transform::Transform;
     use crate::visitor::validator::Validator;
     use regex::Regex;
 
@@ -130,7 +152,8 @@ mod tests {
             "enum": ["meeting", "email"],
             "maxLength": 32,
             "minLength": 8,
-            "pattern": "[a-z]+"
+            "pattern": "[a-z]+",
+            "transforms": ["trim", "lowercase"]
         }"#;
         let field: StringField = serde_json::from_str(field_json).unwrap();
         assert_eq!(field.name, "subtype");
@@ -138,6 +161,10 @@ mod tests {
         assert_eq!(field.max_length.unwrap(), 32);
         assert_eq!(field.min_length.unwrap(), 8);
         assert_eq!(field.pattern.unwrap().pattern.to_string(), "[a-z]+");
+        assert_eq!(
+            field.transforms.unwrap(),
+            vec![Transform::Trim, Transform::Lowercase]
+        );
     }
 
     #[test]
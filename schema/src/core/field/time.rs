@@ -1,5 +1,6 @@
 use crate::core::constraint::common::typed::Type;
 use crate::core::constraint::Constraint;
+use crate::core::field::metadata::{metadata_setters, Metadata};
 use crate::core::field::{Field, FieldType};
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +8,8 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "type", rename = "time")]
 pub struct TimeField {
     pub name: String,
+    #[serde(flatten)]
+    pub metadata: Metadata,
 }
 
 impl Field for TimeField {
@@ -16,6 +19,10 @@ impl Field for TimeField {
         self.name.clone()
     }
 
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     fn constrains(&self) -> Vec<Box<dyn Constraint>> {
         vec![Box::new(Type {
             typed: Self::FIELD_TYPE,
@@ -26,6 +33,7 @@ impl Field for TimeField {
 #[derive(Default)]
 pub struct TimeFieldBuilder {
     name: String,
+    metadata: Metadata,
 }
 
 impl TimeFieldBuilder {
@@ -38,8 +46,13 @@ impl TimeFieldBuilder {
         self
     }
 
+    metadata_setters!();
+
     pub fn build(self) -> TimeField {
-        TimeField { name: self.name }
+        TimeField {
+            name: self.name,
+            metadata: self.metadata,
+        }
     }
 }
 
@@ -5,6 +5,7 @@ use crate::core::constraint::number::exclusive_minimum::ExclusiveMinimum;
 use crate::core::constraint::number::maximum::Maximum;
 use crate::core::constraint::number::minimum::Minimum;
 use crate::core::constraint::Constraint;
+use crate::core::field::metadata::{metadata_setters, Metadata};
 use crate::core::field::{Field, FieldType};
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +13,8 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "type", rename = "uinteger")]
 pub struct UIntegerField {
     pub name: String,
+    #[serde(flatten)]
+    pub metadata: Metadata,
     #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
     pub enumeration: Option<Vec<u64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -31,6 +34,10 @@ impl Field for UIntegerField {
         self.name.clone()
     }
 
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     fn constrains(&self) -> Vec<Box<dyn Constraint>> {
         let mut constraints: Vec<Box<dyn Constraint>> = vec![Box::new(Type {
             typed: Self::FIELD_TYPE,
@@ -57,6 +64,7 @@ impl Field for UIntegerField {
 #[derive(Default)]
 pub struct UIntegerFieldBuilder {
     name: String,
+    metadata: Metadata,
     enumeration: Option<Vec<u64>>,
     maximum: Option<u64>,
     exclusive_maximum: Option<u64>,
@@ -74,6 +82,8 @@ impl UIntegerFieldBuilder {
         self
     }
 
+    metadata_setters!();
+
     pub fn enumeration(mut self, numbers: Vec<u64>) -> Self {
         self.enumeration = Some(numbers);
         self
@@ -102,6 +112,7 @@ impl UIntegerFieldBuilder {
     pub fn build(self) -> UIntegerField {
         UIntegerField {
             name: self.name,
+            metadata: self.metadata,
             enumeration: self.enumeration,
             maximum: self.maximum,
             exclusive_maximum: self.exclusive_maximum,
@@ -0,0 +1,308 @@
+use crate::core::field::array::ArrayFieldBuilder;
+use crate::core::field::boolean::BooleanFieldBuilder;
+use crate::core::field::float::FloatFieldBuilder;
+use crate::core::field::integer::IntegerFieldBuilder;
+use crate::core::field::object::{ObjectField, ObjectFieldBuilder};
+use crate::core::field::reference::ReferenceFieldBuilder;
+use crate::core::field::string::StringFieldBuilder;
+use crate::core::field::uinteger::UIntegerFieldBuilder;
+use crate::core::field::{FieldEnum, FIELD_TYPES};
+use crate::core::transform::Transform;
+use crate::visitor::json::from_json;
+use crate::visitor::validator::{ValidationError, Validator, ValidatorOptions};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Kind {
+    Type,
+    String,
+    UInteger,
+    Integer,
+    Float,
+    Boolean,
+    Any,
+    Array,
+    Strings,
+    Names,
+    UIntegers,
+    Integers,
+    Floats,
+    Pattern,
+    Transforms,
+    Field,
+    Properties,
+}
+
+impl Kind {
+    pub(crate) fn expected(&self) -> &'static str {
+        match self {
+            Kind::Type => "one of the field types",
+            Kind::String => "a string",
+            Kind::UInteger => "an unsigned integer",
+            Kind::Integer => "an integer",
+            Kind::Float => "a number",
+            Kind::Boolean => "a boolean",
+            Kind::Any => "any value",
+            Kind::Array => "an array",
+            Kind::Strings => "an array of strings",
+            Kind::Names => "an array of unique strings",
+            Kind::UIntegers => "an array of unsigned integers",
+            Kind::Integers => "an array of integers",
+            Kind::Floats => "an array of numbers",
+            Kind::Pattern => "a regular expression",
+            Kind::Transforms => "an array of transforms",
+            Kind::Field => "a field",
+            Kind::Properties => "an object of fields",
+        }
+    }
+
+    fn join(self, other: Kind) -> Kind {
+        match (self, other) {
+            _ if self == other => self,
+            (Kind::UInteger | Kind::Integer | Kind::Float, _) => Kind::Float,
+            _ => Kind::Array,
+        }
+    }
+
+    /// The values of [Kind::Any] are not checked.
+    fn field(self, name: &'static str) -> Option<FieldEnum> {
+        let schema = |name| ReferenceFieldBuilder::new().name(name).reference("schema");
+        Some(match self {
+            Kind::Type => StringFieldBuilder::new()
+                .name(name)
+                .enumeration(FIELD_TYPES.iter().map(|t| t.to_string()).collect())
+                .build()
+                .into(),
+            Kind::String | Kind::Pattern => StringFieldBuilder::new().name(name).build().into(),
+            Kind::UInteger => UIntegerFieldBuilder::new().name(name).build().into(),
+            Kind::Integer => IntegerFieldBuilder::new().name(name).build().into(),
+            Kind::Float => FloatFieldBuilder::new().name(name).build().into(),
+            Kind::Boolean => BooleanFieldBuilder::new().name(name).build().into(),
+            Kind::Any => return None,
+            Kind::Array | Kind::UIntegers | Kind::Integers | Kind::Floats => {
+                ArrayFieldBuilder::new().name(name).build().into()
+            }
+            Kind::Strings | Kind::Names => ArrayFieldBuilder::new()
+                .name(name)
+                .item(StringFieldBuilder::new().name("string").build())
+                .unique(self == Kind::Names)
+                .build()
+                .into(),
+            Kind::Transforms => ArrayFieldBuilder::new()
+                .name(name)
+                .item(
+                    StringFieldBuilder::new()
+                        .name("transform")
+                        .enumeration(
+                            Transform::ALL
+                                .iter()
+                                .map(|t| {
+                                    serde_json::to_value(t)
+                                        .unwrap()
+                                        .as_str()
+                                        .unwrap()
+                                        .to_string()
+                                })
+                                .collect(),
+                        )
+                        .build(),
+                )
+                .build()
+                .into(),
+            Kind::Field => schema(name).build().into(),
+            Kind::Properties => ObjectFieldBuilder::new()
+                .name(name)
+                .additional_properties(schema("property").build())
+                .build()
+                .into(),
+        })
+    }
+}
+
+pub(crate) struct Keyword {
+    pub(crate) name: &'static str,
+    pub(crate) kind: Kind,
+    /// Every type if empty.
+    pub(crate) types: &'static [&'static str],
+    pub(crate) required: bool,
+}
+
+impl Keyword {
+    const fn new(name: &'static str, kind: Kind, types: &'static [&'static str]) -> Self {
+        Keyword {
+            name,
+            kind,
+            types,
+            required: false,
+        }
+    }
+
+    const fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+}
+
+/// Every keyword of the schema language, a keyword whose value depends on the type of field
+/// (e.g. `maximum`) is listed once for every type.
+pub(crate) const KEYWORDS: &[Keyword] = &[
+    Keyword::new("type", Kind::Type, &[]).required(),
+    Keyword::new("name", Kind::String, &[]).required(),
+    Keyword::new("title", Kind::String, &[]),
+    Keyword::new("description", Kind::String, &[]),
+    Keyword::new("examples", Kind::Array, &[]),
+    Keyword::new("default", Kind::Any, &[]),
+    Keyword::new("deprecated", Kind::Boolean, &[]),
+    Keyword::new("readOnly", Kind::Boolean, &[]),
+    Keyword::new("writeOnly", Kind::Boolean, &[]),
+    Keyword::new("item", Kind::Field, &["array"]),
+    Keyword::new("unique", Kind::Boolean, &["array"]),
+    Keyword::new("enum", Kind::Floats, &["float"]),
+    Keyword::new("maximum", Kind::Float, &["float"]),
+    Keyword::new("exclusiveMaximum", Kind::Float, &["float"]),
+    Keyword::new("minimum", Kind::Float, &["float"]),
+    Keyword::new("exclusiveMinimum", Kind::Float, &["float"]),
+    Keyword::new("enum", Kind::Integers, &["integer"]),
+    Keyword::new("maximum", Kind::Integer, &["integer"]),
+    Keyword::new("exclusiveMaximum", Kind::Integer, &["integer"]),
+    Keyword::new("minimum", Kind::Integer, &["integer"]),
+    Keyword::new("exclusiveMinimum", Kind::Integer, &["integer"]),
+    Keyword::new("enum", Kind::UIntegers, &["uinteger"]),
+    Keyword::new("maximum", Kind::UInteger, &["uinteger"]),
+    Keyword::new("exclusiveMaximum", Kind::UInteger, &["uinteger"]),
+    Keyword::new("minimum", Kind::UInteger, &["uinteger"]),
+    Keyword::new("exclusiveMinimum", Kind::UInteger, &["uinteger"]),
+    Keyword::new("properties", Kind::Properties, &["object"]).required(),
+    Keyword::new("required", Kind::Names, &["object"]),
+    Keyword::new("additionalProperties", Kind::Field, &["object"]),
+    Keyword::new("enum", Kind::Strings, &["string"]),
+    Keyword::new("maxLength", Kind::UInteger, &["string"]),
+    Keyword::new("minLength", Kind::UInteger, &["string"]),
+    Keyword::new("pattern", Kind::Pattern, &["string"]),
+    Keyword::new("transforms", Kind::Transforms, &["string"]),
+    Keyword::new("reference", Kind::String, &["reference"]).required(),
+];
+
+pub(crate) fn keywords(field_type: &str) -> impl Iterator<Item = &'static Keyword> + '_ {
+    KEYWORDS
+        .iter()
+        .filter(move |k| k.types.is_empty() || k.types.contains(&field_type))
+}
+
+/// The schema of the schema language itself, so a schema document can be validated by
+/// [Validator] with errors located by keyword, e.g. `schema/properties/first_name/minLength`.
+pub fn meta_schema() -> ObjectField {
+    let mut kinds: Vec<(&'static str, Kind)> = vec![];
+    for keyword in KEYWORDS {
+        match kinds.iter_mut().find(|(name, _)| *name == keyword.name) {
+            Some((_, kind)) => *kind = kind.join(keyword.kind),
+            None => kinds.push((keyword.name, keyword.kind)),
+        }
+    }
+    let mut schema = ObjectFieldBuilder::new()
+        .name("schema")
+        .required(
+            KEYWORDS
+                .iter()
+                .filter(|k| k.required && k.types.is_empty())
+                .map(|k| k.name.to_string())
+                .collect(),
+        )
+        .build();
+    for (name, kind) in kinds {
+        if let Some(field) = kind.field(name) {
+            schema.properties.insert(name.to_string(), Box::new(field));
+        }
+    }
+    schema
+}
+
+fn strip_nulls(value: &Value) -> Value {
+    match value {
+        Value::Object(o) => Value::Object(
+            o.iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k.clone(), strip_nulls(v)))
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
+/// The keywords set to null are treated as absent, the same as deserializing the document.
+pub fn validate_schema(schema: &Value) -> Result<(), BTreeMap<String, Vec<ValidationError>>> {
+    static VALIDATOR: OnceLock<Validator> = OnceLock::new();
+    let validator = VALIDATOR.get_or_init(|| {
+        Validator::with_options(meta_schema(), ValidatorOptions::new().indexed_paths(true))
+    });
+    validator.validate(&from_json(validator.schema(), &strip_nulls(schema)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::core::meta::{meta_schema, validate_schema};
+    use crate::visitor::lint::lint;
+    use serde_json::json;
+
+    #[test]
+    fn test_meta_schema() {
+        assert!(lint(&meta_schema().into()).is_empty());
+
+        // the meta-schema is a valid schema document itself
+        let document = serde_json::to_value(meta_schema()).unwrap();
+        assert!(validate_schema(&document).is_ok());
+        assert!(serde_json::from_value::<FieldEnum>(document).is_ok());
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        let document = json!({
+            "type": "object",
+            "name": "client",
+            "properties": {
+                "first_name": {
+                    "type": "string",
+                    "name": "first_name",
+                    "title": 1,
+                    "minLength": -1,
+                    "transforms": ["trim", "capitalize"]
+                },
+                "tags": {
+                    "type": "array",
+                    "name": "tags",
+                    "item": {
+                        "type": "text",
+                        "name": "tag"
+                    }
+                }
+            },
+            "required": ["first_name", "first_name"]
+        });
+        let errors = validate_schema(&document).err().unwrap();
+        assert_eq!(
+            errors.keys().collect::<Vec<_>>(),
+            vec![
+                "schema/properties/first_name/minLength",
+                "schema/properties/first_name/title",
+                "schema/properties/first_name/transforms/1",
+                "schema/properties/tags/item/type",
+                "schema/required",
+            ]
+        );
+
+        assert!(validate_schema(&json!({
+            "type": "array",
+            "name": "tags",
+            "item": null,
+            "unique": null
+        }))
+        .is_ok());
+        assert!(validate_schema(&json!({"type": "string"}))
+            .err()
+            .unwrap()
+            .contains_key("schema"));
+    }
+}
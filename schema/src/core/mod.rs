@@ -1,3 +1,6 @@
 pub mod constraint;
 pub mod field;
+pub mod meta;
+pub mod parse;
+pub mod transform;
 pub mod value;
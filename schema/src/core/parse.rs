@@ -0,0 +1,349 @@
+use crate::core::constraint::string::pattern::Pattern;
+use crate::core::field::FieldEnum;
+use crate::core::field::FIELD_TYPES;
+use crate::core::meta::{keywords, Kind};
+use crate::core::transform::Transform;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// An error found in a schema document, located by the JSON pointer of the keyword.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaError {
+    pub path: String,
+    pub message: String,
+    pub expected: Option<String>,
+    /// The closest known keyword (or field type) to a misspelled one.
+    pub suggestion: Option<String>,
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        write!(f, "{}: {}", path, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean {}?)", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for SchemaError {}
+
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    strict: bool,
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        ParseOptions::default()
+    }
+
+    /// Rejects the keywords unknown to the type of the field, except the `x-` extensions.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+}
+
+fn suggest<'a>(word: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    candidates
+        .map(|c| {
+            (
+                strsim::jaro_winkler(&word.to_lowercase(), &c.to_lowercase()),
+                c,
+            )
+        })
+        .filter(|(similarity, _)| *similarity > 0.8)
+        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, c)| c.to_string())
+}
+
+fn segment(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+struct State {
+    strict: bool,
+    path: Vec<String>,
+    errors: Vec<SchemaError>,
+}
+
+impl State {
+    fn report(&mut self, message: String, expected: Option<&str>, suggestion: Option<String>) {
+        self.errors.push(SchemaError {
+            path: self.path.concat(),
+            message,
+            expected: expected.map(|e| e.to_string()),
+            suggestion,
+        })
+    }
+
+    fn check_as<T: DeserializeOwned>(&mut self, value: &Value, kind: Kind) {
+        if let Err(e) = serde_json::from_value::<T>(value.clone()) {
+            let message = match (kind, value) {
+                // the error of an invalid regular expression is more helpful than the type
+                (Kind::Pattern, Value::String(_)) => e.to_string(),
+                _ => format!("expected {}, found {}", kind.expected(), value),
+            };
+            self.report(message, Some(kind.expected()), None);
+        }
+    }
+
+    fn check_keyword(&mut self, value: &Value, kind: Kind) {
+        match kind {
+            Kind::Type | Kind::String => self.check_as::<String>(value, kind),
+            Kind::UInteger => self.check_as::<u64>(value, kind),
+            Kind::Integer => self.check_as::<i64>(value, kind),
+            Kind::Float => self.check_as::<f64>(value, kind),
+            Kind::Boolean => self.check_as::<bool>(value, kind),
+            Kind::Any => {}
+            Kind::Array => self.check_as::<Vec<Value>>(value, kind),
+            Kind::Strings | Kind::Names => self.check_as::<Vec<String>>(value, kind),
+            Kind::UIntegers => self.check_as::<Vec<u64>>(value, kind),
+            Kind::Integers => self.check_as::<Vec<i64>>(value, kind),
+            Kind::Floats => self.check_as::<Vec<f64>>(value, kind),
+            Kind::Pattern => self.check_as::<Pattern>(value, kind),
+            Kind::Transforms => self.check_as::<Vec<Transform>>(value, kind),
+            Kind::Field => self.check_field(value),
+            Kind::Properties => match value {
+                Value::Object(properties) => {
+                    for (key, property) in properties {
+                        self.path.push(format!("/{}", segment(key)));
+                        self.check_field(property);
+                        self.path.pop();
+                    }
+                }
+                _ => self.report(
+                    format!("expected {}, found {}", kind.expected(), value),
+                    Some(kind.expected()),
+                    None,
+                ),
+            },
+        }
+    }
+
+    fn check_field(&mut self, value: &Value) {
+        let Value::Object(field) = value else {
+            let expected = Kind::Field.expected();
+            return self.report(
+                format!("expected {}, found {}", expected, value),
+                Some(expected),
+                None,
+            );
+        };
+        let Some(field_type) = self.check_type(field) else {
+            return;
+        };
+
+        for keyword in keywords(field_type).filter(|k| k.required) {
+            if !field.contains_key(keyword.name) {
+                self.report(format!("missing keyword {}", keyword.name), None, None);
+            }
+        }
+        for (name, value) in field {
+            self.path.push(format!("/{}", segment(name)));
+            match keywords(field_type).find(|k| k.name == name) {
+                // null is deserialized as an absent optional keyword
+                Some(keyword) if !value.is_null() || keyword.required => {
+                    self.check_keyword(value, keyword.kind)
+                }
+                Some(_) => {}
+                None if self.strict && !name.starts_with("x-") => self.report(
+                    format!("unknown keyword {} for {} field", name, field_type),
+                    None,
+                    suggest(name, keywords(field_type).map(|k| k.name)),
+                ),
+                None => {}
+            }
+            self.path.pop();
+        }
+    }
+
+    fn check_type<'a>(&mut self, field: &'a Map<String, Value>) -> Option<&'a str> {
+        let expected = Some(Kind::Type.expected());
+        match field.get("type") {
+            None => {
+                self.report("missing keyword type".to_string(), None, None);
+                None
+            }
+            Some(Value::String(t)) if FIELD_TYPES.contains(&t.as_str()) => Some(t),
+            Some(t) => {
+                self.path.push("/type".to_string());
+                let suggestion = t
+                    .as_str()
+                    .and_then(|t| suggest(t, FIELD_TYPES.iter().copied()));
+                self.report(format!("unknown field type {}", t), expected, suggestion);
+                self.path.pop();
+                None
+            }
+        }
+    }
+}
+
+/// Deserializes a schema document, reporting every error by the JSON pointer of the keyword.
+pub fn from_value(value: &Value, options: &ParseOptions) -> Result<FieldEnum, Vec<SchemaError>> {
+    let mut state = State {
+        strict: options.strict,
+        path: vec![],
+        errors: vec![],
+    };
+    state.check_field(value);
+    if !state.errors.is_empty() {
+        return Err(state.errors);
+    }
+    serde_json::from_value(value.clone()).map_err(|e| {
+        vec![SchemaError {
+            path: String::new(),
+            message: e.to_string(),
+            expected: None,
+            suggestion: None,
+        }]
+    })
+}
+
+pub fn from_str(json: &str, options: &ParseOptions) -> Result<FieldEnum, Vec<SchemaError>> {
+    let value: Value = serde_json::from_str(json).map_err(|e| {
+        vec![SchemaError {
+            path: String::new(),
+            message: e.to_string(),
+            expected: None,
+            suggestion: None,
+        }]
+    })?;
+    from_value(&value, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::core::parse::{from_str, from_value, ParseOptions, SchemaError};
+    use serde_json::json;
+
+    fn client(first_name: serde_json::Value) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "name": "client",
+            "properties": {
+                "first_name": first_name
+            },
+            "required": ["first_name"]
+        })
+    }
+
+    #[test]
+    fn test_valid() {
+        let document = client(json!({
+            "type": "string",
+            "name": "first_name",
+            "minLength": 1,
+            "pattern": "[a-z]+",
+            "transforms": ["trim", "lowercase"],
+            "description": "The first name",
+            "default": "Robert",
+            "placeholder": "ignored unless strict"
+        }));
+        let field = from_value(&document, &ParseOptions::new()).unwrap();
+        assert!(matches!(field, FieldEnum::Object(_)));
+
+        let errors = from_value(
+            &client(json!({"type": "string", "name": "first_name", "deprecated": "yes"})),
+            &ParseOptions::new(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            errors[0].to_string(),
+            "/properties/first_name/deprecated: expected a boolean, found \"yes\""
+        );
+    }
+
+    #[test]
+    fn test_type() {
+        let errors = from_value(
+            &client(json!({"type": "string", "name": "first_name", "minLength": -1})),
+            &ParseOptions::new(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            errors,
+            vec![SchemaError {
+                path: "/properties/first_name/minLength".to_string(),
+                message: "expected an unsigned integer, found -1".to_string(),
+                expected: Some("an unsigned integer".to_string()),
+                suggestion: None,
+            }]
+        );
+
+        let errors = from_value(
+            &client(json!({"type": "string", "name": "first_name", "pattern": "[a-z"})),
+            &ParseOptions::new(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(errors[0].path, "/properties/first_name/pattern");
+        assert!(errors[0].message.contains("unclosed character class"));
+    }
+
+    #[test]
+    fn test_field_type() {
+        let errors = from_value(
+            &client(json!({"type": "strng", "name": "first_name"})),
+            &ParseOptions::new(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            errors[0].to_string(),
+            "/properties/first_name/type: unknown field type \"strng\" (did you mean string?)"
+        );
+
+        let errors = from_str(r#"{"name": "client"}"#, &ParseOptions::new())
+            .err()
+            .unwrap();
+        assert_eq!(errors[0].to_string(), "/: missing keyword type");
+    }
+
+    #[test]
+    fn test_strict() {
+        let document = client(json!({
+            "type": "string",
+            "name": "first_name",
+            "minLenght": 1,
+            "maximum": 10,
+            "x-widget": "text"
+        }));
+        assert!(from_value(&document, &ParseOptions::new()).is_ok());
+
+        let errors = from_value(&document, &ParseOptions::new().strict(true))
+            .err()
+            .unwrap();
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "/properties/first_name/maximum: unknown keyword maximum for string field",
+                "/properties/first_name/minLenght: unknown keyword minLenght for string field (did you mean minLength?)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_syntax() {
+        let errors = from_str(r#"{"type": "string""#, &ParseOptions::new())
+            .err()
+            .unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "");
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Transform {
+    Trim,
+    Lowercase,
+    Uppercase,
+    CollapseWhitespace,
+    Nfc,
+    Nfkc,
+}
+
+impl Transform {
+    pub const ALL: [Transform; 6] = [
+        Transform::Trim,
+        Transform::Lowercase,
+        Transform::Uppercase,
+        Transform::CollapseWhitespace,
+        Transform::Nfc,
+        Transform::Nfkc,
+    ];
+
+    pub fn apply(&self, s: &str) -> String {
+        match self {
+            Transform::Trim => s.trim().to_string(),
+            Transform::Lowercase => s.to_lowercase(),
+            Transform::Uppercase => s.to_uppercase(),
+            Transform::CollapseWhitespace => s.split_whitespace().collect::<Vec<_>>().join(" "),
+            Transform::Nfc => s.nfc().collect(),
+            Transform::Nfkc => s.nfkc().collect(),
+        }
+    }
+}
+
+pub fn apply(transforms: &[Transform], s: &str) -> String {
+    transforms
+        .iter()
+        .fold(s.to_string(), |s, transform| transform.apply(&s))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::transform::{apply, Transform};
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(Transform::Trim.apply("  Robert Li \n"), "Robert Li");
+        assert_eq!(Transform::Lowercase.apply("Robert"), "robert");
+        assert_eq!(Transform::Uppercase.apply("Robert"), "ROBERT");
+        assert_eq!(
+            Transform::CollapseWhitespace.apply(" Robert \t  Li "),
+            "Robert Li"
+        );
+        assert_eq!(Transform::Nfc.apply("e\u{301}"), "\u{e9}");
+        assert_eq!(Transform::Nfkc.apply("\u{fb01}"), "fi");
+        assert_eq!(
+            apply(&[Transform::Trim, Transform::Lowercase], "  Robert  "),
+            "robert"
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&[Transform::CollapseWhitespace, Transform::Nfkc]).unwrap(),
+            r#"["collapse_whitespace","nfkc"]"#
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
 use email_address_parser::EmailAddress;
 use secrecy::{ExposeSecret, Secret, Zeroize};
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 
@@ -39,109 +40,295 @@ impl Display for FieldValue {
     }
 }
 
+/// A borrowed view of [FieldValue], so a value can be validated without copying it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValueRef<'a> {
+    String(Cow<'a, str>),
+    Integer(i64),
+    UInteger(u64),
+    Float(f64),
+    Boolean(bool),
+    Object(BTreeMap<Cow<'a, str>, FieldValueRef<'a>>),
+    Array(Vec<FieldValueRef<'a>>),
+    Email(Cow<'a, EmailAddress>),
+    DateTime(DateTime<Utc>),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Null,
+}
+
+impl Display for FieldValueRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldValueRef::String(v) => write!(f, "{}", v),
+            FieldValueRef::Integer(v) => write!(f, "{}", v),
+            FieldValueRef::UInteger(v) => write!(f, "{}", v),
+            FieldValueRef::Float(v) => write!(f, "{}", v),
+            FieldValueRef::Boolean(v) => write!(f, "{}", v),
+            FieldValueRef::Object(_) => write!(f, "Object(...)"),
+            FieldValueRef::Array(_) => write!(f, "Array(...)"),
+            FieldValueRef::Email(v) => write!(f, "{}", v),
+            FieldValueRef::DateTime(v) => write!(f, "{}", v),
+            FieldValueRef::Date(v) => write!(f, "{}", v),
+            FieldValueRef::Time(v) => write!(f, "{}", v),
+            FieldValueRef::Null => write!(f, "null"),
+        }
+    }
+}
+
+impl<'a> From<&'a FieldValue> for FieldValueRef<'a> {
+    fn from(value: &'a FieldValue) -> Self {
+        match value {
+            FieldValue::String(v) => FieldValueRef::String(Cow::Borrowed(v)),
+            FieldValue::Integer(v) => FieldValueRef::Integer(*v),
+            FieldValue::UInteger(v) => FieldValueRef::UInteger(*v),
+            FieldValue::Float(v) => FieldValueRef::Float(*v),
+            FieldValue::Boolean(v) => FieldValueRef::Boolean(*v),
+            FieldValue::Object(o) => FieldValueRef::Object(
+                o.iter()
+                    .map(|(k, v)| (Cow::Borrowed(k.as_str()), v.into()))
+                    .collect(),
+            ),
+            FieldValue::Array(a) => FieldValueRef::Array(a.iter().map(|v| v.into()).collect()),
+            FieldValue::Email(v) => FieldValueRef::Email(Cow::Borrowed(v)),
+            FieldValue::DateTime(v) => FieldValueRef::DateTime(*v),
+            FieldValue::Date(v) => FieldValueRef::Date(*v),
+            FieldValue::Time(v) => FieldValueRef::Time(*v),
+            FieldValue::Null => FieldValueRef::Null,
+        }
+    }
+}
+
+impl From<FieldValue> for FieldValueRef<'_> {
+    fn from(value: FieldValue) -> Self {
+        match value {
+            FieldValue::String(v) => FieldValueRef::String(Cow::Owned(v)),
+            FieldValue::Integer(v) => FieldValueRef::Integer(v),
+            FieldValue::UInteger(v) => FieldValueRef::UInteger(v),
+            FieldValue::Float(v) => FieldValueRef::Float(v),
+            FieldValue::Boolean(v) => FieldValueRef::Boolean(v),
+            FieldValue::Object(o) => FieldValueRef::Object(
+                o.into_iter()
+                    .map(|(k, v)| (Cow::Owned(k), v.into()))
+                    .collect(),
+            ),
+            FieldValue::Array(a) => FieldValueRef::Array(a.into_iter().map(|v| v.into()).collect()),
+            FieldValue::Email(v) => FieldValueRef::Email(Cow::Owned(v)),
+            FieldValue::DateTime(v) => FieldValueRef::DateTime(v),
+            FieldValue::Date(v) => FieldValueRef::Date(v),
+            FieldValue::Time(v) => FieldValueRef::Time(v),
+            FieldValue::Null => FieldValueRef::Null,
+        }
+    }
+}
+
+impl From<&FieldValueRef<'_>> for FieldValue {
+    fn from(value: &FieldValueRef<'_>) -> Self {
+        match value {
+            FieldValueRef::String(v) => FieldValue::String(v.to_string()),
+            FieldValueRef::Integer(v) => FieldValue::Integer(*v),
+            FieldValueRef::UInteger(v) => FieldValue::UInteger(*v),
+            FieldValueRef::Float(v) => FieldValue::Float(*v),
+            FieldValueRef::Boolean(v) => FieldValue::Boolean(*v),
+            FieldValueRef::Object(o) => {
+                FieldValue::Object(o.iter().map(|(k, v)| (k.to_string(), v.into())).collect())
+            }
+            FieldValueRef::Array(a) => FieldValue::Array(a.iter().map(|v| v.into()).collect()),
+            FieldValueRef::Email(v) => FieldValue::Email(v.as_ref().clone()),
+            FieldValueRef::DateTime(v) => FieldValue::DateTime(*v),
+            FieldValueRef::Date(v) => FieldValue::Date(*v),
+            FieldValueRef::Time(v) => FieldValue::Time(*v),
+            FieldValueRef::Null => FieldValue::Null,
+        }
+    }
+}
+
 pub trait Reflect {
     fn field_value(&self) -> FieldValue;
+
+    /// Falls back to the owned [Reflect::field_value] by default.
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        self.field_value().into()
+    }
+}
+
+impl Reflect for FieldValue {
+    fn field_value(&self) -> FieldValue {
+        self.clone()
+    }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        self.into()
+    }
 }
 
 impl Reflect for i8 {
     fn field_value(&self) -> FieldValue {
         FieldValue::Integer(*self as i64)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Integer(*self as i64)
+    }
 }
 
 impl Reflect for i16 {
     fn field_value(&self) -> FieldValue {
         FieldValue::Integer(*self as i64)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Integer(*self as i64)
+    }
 }
 
 impl Reflect for i32 {
     fn field_value(&self) -> FieldValue {
         FieldValue::Integer(*self as i64)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Integer(*self as i64)
+    }
 }
 
 impl Reflect for i64 {
     fn field_value(&self) -> FieldValue {
         FieldValue::Integer(*self)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Integer(*self)
+    }
 }
 
 impl Reflect for u8 {
     fn field_value(&self) -> FieldValue {
         FieldValue::UInteger(*self as u64)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::UInteger(*self as u64)
+    }
 }
 
 impl Reflect for u16 {
     fn field_value(&self) -> FieldValue {
         FieldValue::UInteger(*self as u64)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::UInteger(*self as u64)
+    }
 }
 
 impl Reflect for u32 {
     fn field_value(&self) -> FieldValue {
         FieldValue::UInteger(*self as u64)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::UInteger(*self as u64)
+    }
 }
 
 impl Reflect for u64 {
     fn field_value(&self) -> FieldValue {
         FieldValue::UInteger(*self)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::UInteger(*self)
+    }
 }
 
 impl Reflect for f32 {
     fn field_value(&self) -> FieldValue {
         FieldValue::Float(*self as f64)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Float(*self as f64)
+    }
 }
 
 impl Reflect for f64 {
     fn field_value(&self) -> FieldValue {
         FieldValue::Float(*self)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Float(*self)
+    }
 }
 
 impl Reflect for bool {
     fn field_value(&self) -> FieldValue {
         FieldValue::Boolean(*self)
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Boolean(*self)
+    }
 }
 
 impl Reflect for &'static str {
     fn field_value(&self) -> FieldValue {
         FieldValue::String(self.to_string())
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::String(Cow::Borrowed(self))
+    }
 }
 
 impl Reflect for String {
     fn field_value(&self) -> FieldValue {
         FieldValue::String(self.clone())
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::String(Cow::Borrowed(self))
+    }
 }
 
 impl Reflect for EmailAddress {
     fn field_value(&self) -> FieldValue {
         FieldValue::Email(self.clone())
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Email(Cow::Borrowed(self))
+    }
 }
 
 impl<Tz: TimeZone> Reflect for DateTime<Tz> {
     fn field_value(&self) -> FieldValue {
         FieldValue::DateTime(self.with_timezone(&Utc))
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::DateTime(self.with_timezone(&Utc))
+    }
 }
 
 impl Reflect for NaiveDate {
     fn field_value(&self) -> FieldValue {
-        FieldValue::Date(self.clone())
+        FieldValue::Date(*self)
+    }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Date(*self)
     }
 }
 
 impl Reflect for NaiveTime {
     fn field_value(&self) -> FieldValue {
-        FieldValue::Time(self.clone())
+        FieldValue::Time(*self)
+    }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Time(*self)
     }
 }
 
@@ -149,6 +336,10 @@ impl<T: Reflect + Zeroize> Reflect for Secret<T> {
     fn field_value(&self) -> FieldValue {
         self.expose_secret().field_value()
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        self.expose_secret().field_value_ref()
+    }
 }
 
 impl<T: Reflect> Reflect for Option<T> {
@@ -158,10 +349,68 @@ impl<T: Reflect> Reflect for Option<T> {
             Some(v) => v.field_value(),
         }
     }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        match self {
+            None => FieldValueRef::Null,
+            Some(v) => v.field_value_ref(),
+        }
+    }
 }
 
 impl<T: Reflect> Reflect for Vec<T> {
     fn field_value(&self) -> FieldValue {
-        FieldValue::Array(self.into_iter().map(|v| v.field_value()).collect())
+        FieldValue::Array(self.iter().map(|v| v.field_value()).collect())
+    }
+
+    fn field_value_ref(&self) -> FieldValueRef<'_> {
+        FieldValueRef::Array(self.iter().map(|v| v.field_value_ref()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::value::{FieldValue, FieldValueRef, Reflect};
+    use std::borrow::Cow;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_field_value_ref() {
+        let tags = vec!["meeting".to_string(), "email".to_string()];
+        assert_eq!(
+            tags.field_value_ref(),
+            FieldValueRef::Array(vec![
+                FieldValueRef::String(Cow::Borrowed("meeting")),
+                FieldValueRef::String(Cow::Borrowed("email"))
+            ])
+        );
+        assert_eq!(
+            FieldValue::from(&tags.field_value_ref()),
+            tags.field_value()
+        );
+    }
+
+    #[test]
+    fn test_field_value_ref_fallback() {
+        struct Document {
+            title: String,
+        }
+
+        impl Reflect for Document {
+            fn field_value(&self) -> FieldValue {
+                FieldValue::Object(BTreeMap::from([(
+                    "title".to_string(),
+                    self.title.field_value(),
+                )]))
+            }
+        }
+
+        let document = Document {
+            title: "Document Title".to_string(),
+        };
+        assert_eq!(
+            document.field_value_ref(),
+            FieldValueRef::from(&document.field_value())
+        );
     }
 }
@@ -0,0 +1,245 @@
+use crate::core::field::FieldEnum;
+use crate::registry::{admit, check_name, RegistryError, SchemaRegistry};
+use crate::visitor::compatibility::CompatibilityMode;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+const LATEST: &str = "latest";
+
+/// Keeps the schemas in a directory, e.g. `client/1.json`, `client/2.json` and `client/latest`.
+/// The files are never overwritten in place, so several processes may read while one registers.
+#[derive(Debug)]
+pub struct FileRegistry {
+    root: PathBuf,
+    mode: CompatibilityMode,
+}
+
+impl FileRegistry {
+    pub fn open(root: impl AsRef<Path>) -> Result<Self, RegistryError> {
+        fs::create_dir_all(root.as_ref())?;
+        Ok(FileRegistry {
+            root: root.as_ref().to_path_buf(),
+            mode: CompatibilityMode::default(),
+        })
+    }
+
+    pub fn compatibility(mut self, mode: CompatibilityMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    fn directory(&self, name: &str) -> Result<PathBuf, RegistryError> {
+        check_name(name)?;
+        let directory = self.root.join(name);
+        if directory.is_dir() {
+            Ok(directory)
+        } else {
+            Err(RegistryError::NotFound(name.to_string()))
+        }
+    }
+
+    /// A directory without any version yet has no latest version.
+    fn latest_version(&self, name: &str) -> Result<Option<u32>, RegistryError> {
+        let latest = match fs::read_to_string(self.directory(name)?.join(LATEST)) {
+            Ok(latest) => latest,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Ok(self.versions(name)?.last().copied());
+            }
+            Err(e) => return Err(e.into()),
+        };
+        latest.trim().parse().map(Some).map_err(|_| {
+            RegistryError::Io(std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid latest version {:?} of schema {}", latest, name),
+            ))
+        })
+    }
+
+    fn write_latest(&self, directory: &Path, version: u32) -> Result<(), RegistryError> {
+        let temporary = directory.join(format!(".{}.{}", LATEST, uuid::Uuid::new_v4()));
+        fs::write(&temporary, version.to_string())?;
+        fs::rename(&temporary, directory.join(LATEST))?;
+        Ok(())
+    }
+
+    /// A link never replaces an existing file, so a version taken concurrently is skipped.
+    fn write_version(
+        &self,
+        directory: &Path,
+        mut version: u32,
+        document: &[u8],
+    ) -> Result<u32, RegistryError> {
+        let temporary = directory.join(format!(".{}", uuid::Uuid::new_v4()));
+        fs::write(&temporary, document)?;
+        let linked = loop {
+            match fs::hard_link(&temporary, directory.join(format!("{}.json", version))) {
+                Ok(_) => break Ok(version),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => version += 1,
+                Err(e) => break Err(e),
+            }
+        };
+        fs::remove_file(&temporary)?;
+        Ok(linked?)
+    }
+}
+
+impl SchemaRegistry for FileRegistry {
+    fn register(&mut self, name: &str, schema: FieldEnum) -> Result<u32, RegistryError> {
+        check_name(name)?;
+        let directory = self.root.join(name);
+        let latest = if directory.is_dir() {
+            self.latest_version(name)?
+                .map(|version| self.get(name, version).map(|schema| (version, schema)))
+                .transpose()?
+        } else {
+            None
+        };
+        admit(
+            name,
+            &schema,
+            latest.as_ref().map(|(version, old)| (*version, old)),
+            self.mode,
+        )?;
+        fs::create_dir_all(&directory)?;
+        let document = serde_json::to_vec_pretty(&schema)?;
+        let next = self.versions(name)?.last().map_or(1, |v| v + 1);
+        let version = self.write_version(&directory, next, &document)?;
+        self.write_latest(&directory, version)?;
+        Ok(version)
+    }
+
+    fn get(&self, name: &str, version: u32) -> Result<FieldEnum, RegistryError> {
+        let path = self.directory(name)?.join(format!("{}.json", version));
+        let document = fs::read(path).map_err(|e| match e.kind() {
+            ErrorKind::NotFound => RegistryError::VersionNotFound {
+                name: name.to_string(),
+                version,
+            },
+            _ => e.into(),
+        })?;
+        Ok(serde_json::from_slice(&document)?)
+    }
+
+    fn latest(&self, name: &str) -> Result<(u32, FieldEnum), RegistryError> {
+        let version = self
+            .latest_version(name)?
+            .ok_or_else(|| RegistryError::NotFound(name.to_string()))?;
+        Ok((version, self.get(name, version)?))
+    }
+
+    fn set_latest(&mut self, name: &str, version: u32) -> Result<(), RegistryError> {
+        let directory = self.directory(name)?;
+        if !directory.join(format!("{}.json", version)).is_file() {
+            return Err(RegistryError::VersionNotFound {
+                name: name.to_string(),
+                version,
+            });
+        }
+        self.write_latest(&directory, version)
+    }
+
+    fn versions(&self, name: &str) -> Result<Vec<u32>, RegistryError> {
+        let mut versions = vec![];
+        for entry in fs::read_dir(self.directory(name)?)? {
+            let file_name = entry?.file_name();
+            let version = file_name
+                .to_str()
+                .and_then(|f| f.strip_suffix(".json"))
+                .and_then(|v| v.parse::<u32>().ok());
+            if let Some(version) = version {
+                versions.push(version);
+            }
+        }
+        versions.sort_unstable();
+        Ok(versions)
+    }
+
+    fn names(&self) -> Result<Vec<String>, RegistryError> {
+        let mut names = vec![];
+        for entry in fs::read_dir(&self.root)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            if let Some(name) = entry.file_name().to_str() {
+                // a directory left empty, e.g. by a failed registration, is no schema
+                if check_name(name).is_ok() && !self.versions(name)?.is_empty() {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort_unstable();
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::file::FileRegistry;
+    use crate::registry::tests::{client_schema, exercise};
+    use crate::registry::SchemaRegistry;
+    use crate::visitor::compatibility::CompatibilityMode;
+    use std::fs;
+
+    #[test]
+    fn test_registry() {
+        let root = std::env::temp_dir().join(format!("avocado-{}", uuid::Uuid::new_v4()));
+        exercise(
+            &mut FileRegistry::open(&root)
+                .unwrap()
+                .compatibility(CompatibilityMode::Backward),
+        );
+
+        let mut registry = FileRegistry::open(&root).unwrap();
+        assert_eq!(registry.versions("client").unwrap(), vec![1, 2, 3]);
+        assert_eq!(registry.latest("client").unwrap().0, 3);
+        assert_eq!(
+            fs::read_to_string(root.join("client").join("latest")).unwrap(),
+            "3"
+        );
+        assert_eq!(
+            registry
+                .register("client", client_schema(8, &["email"]))
+                .unwrap(),
+            4
+        );
+        let mut files = fs::read_dir(root.join("client"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(
+            files,
+            vec!["1.json", "2.json", "3.json", "4.json", "latest"]
+        );
+
+        registry.set_latest("client", 2).unwrap();
+        fs::remove_file(root.join("client").join("latest")).unwrap();
+        assert_eq!(registry.latest("client").unwrap().0, 4);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_empty_directory() {
+        let root = std::env::temp_dir().join(format!("avocado-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(root.join("client")).unwrap();
+        let mut registry = FileRegistry::open(&root)
+            .unwrap()
+            .compatibility(CompatibilityMode::Backward);
+        assert!(registry.names().unwrap().is_empty());
+        assert!(registry.latest("client").is_err());
+
+        assert_eq!(
+            registry
+                .register("client", client_schema(8, &["email"]))
+                .unwrap(),
+            1
+        );
+        assert_eq!(registry.names().unwrap(), vec!["client"]);
+        assert_eq!(registry.latest("client").unwrap().0, 1);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
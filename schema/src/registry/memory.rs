@@ -0,0 +1,125 @@
+use crate::core::field::FieldEnum;
+use crate::registry::{admit, RegistryError, SchemaRegistry};
+use crate::visitor::compatibility::CompatibilityMode;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+struct Subject {
+    versions: BTreeMap<u32, Value>,
+    latest: u32,
+}
+
+/// Keeps the schemas in memory, e.g. for tests or the schemas loaded at start up.
+#[derive(Debug, Default)]
+pub struct MemoryRegistry {
+    subjects: BTreeMap<String, Subject>,
+    mode: CompatibilityMode,
+}
+
+impl MemoryRegistry {
+    pub fn new() -> Self {
+        MemoryRegistry::default()
+    }
+
+    pub fn compatibility(mut self, mode: CompatibilityMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    fn subject(&self, name: &str) -> Result<&Subject, RegistryError> {
+        self.subjects
+            .get(name)
+            .ok_or_else(|| RegistryError::NotFound(name.to_string()))
+    }
+}
+
+impl SchemaRegistry for MemoryRegistry {
+    fn register(&mut self, name: &str, schema: FieldEnum) -> Result<u32, RegistryError> {
+        let latest = match self.subjects.get(name) {
+            Some(_) => Some(self.latest(name)?),
+            None => None,
+        };
+        admit(
+            name,
+            &schema,
+            latest.as_ref().map(|(version, old)| (*version, old)),
+            self.mode,
+        )?;
+        let document = serde_json::to_value(schema)?;
+        let subject = self
+            .subjects
+            .entry(name.to_string())
+            .or_insert_with(|| Subject {
+                versions: BTreeMap::new(),
+                latest: 0,
+            });
+        let version = subject.versions.keys().next_back().map_or(1, |v| v + 1);
+        subject.versions.insert(version, document);
+        subject.latest = version;
+        Ok(version)
+    }
+
+    fn get(&self, name: &str, version: u32) -> Result<FieldEnum, RegistryError> {
+        let document = self.subject(name)?.versions.get(&version).ok_or_else(|| {
+            RegistryError::VersionNotFound {
+                name: name.to_string(),
+                version,
+            }
+        })?;
+        Ok(serde_json::from_value(document.clone())?)
+    }
+
+    fn latest(&self, name: &str) -> Result<(u32, FieldEnum), RegistryError> {
+        let version = self.subject(name)?.latest;
+        Ok((version, self.get(name, version)?))
+    }
+
+    fn set_latest(&mut self, name: &str, version: u32) -> Result<(), RegistryError> {
+        let subject = self
+            .subjects
+            .get_mut(name)
+            .ok_or_else(|| RegistryError::NotFound(name.to_string()))?;
+        if !subject.versions.contains_key(&version) {
+            return Err(RegistryError::VersionNotFound {
+                name: name.to_string(),
+                version,
+            });
+        }
+        subject.latest = version;
+        Ok(())
+    }
+
+    fn versions(&self, name: &str) -> Result<Vec<u32>, RegistryError> {
+        Ok(self.subject(name)?.versions.keys().copied().collect())
+    }
+
+    fn names(&self) -> Result<Vec<String>, RegistryError> {
+        Ok(self.subjects.keys().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::memory::MemoryRegistry;
+    use crate::registry::tests::{client_schema, exercise};
+    use crate::registry::SchemaRegistry;
+    use crate::visitor::compatibility::CompatibilityMode;
+
+    #[test]
+    fn test_registry() {
+        exercise(&mut MemoryRegistry::new().compatibility(CompatibilityMode::Backward));
+    }
+
+    #[test]
+    fn test_no_compatibility() {
+        let mut registry = MemoryRegistry::new();
+        registry.register("client", client_schema(64, &[])).unwrap();
+        assert_eq!(
+            registry
+                .register("client", client_schema(8, &["email"]))
+                .unwrap(),
+            2
+        );
+    }
+}
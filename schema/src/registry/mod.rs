@@ -0,0 +1,179 @@
+pub mod file;
+pub mod memory;
+pub mod reload;
+
+use crate::core::field::FieldEnum;
+use crate::visitor::compatibility::{check, CompatibilityMode, CompatibilityReport};
+use crate::visitor::lint::{self, Issue};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RegistryError {
+    #[error("invalid schema name {0:?}")]
+    InvalidName(String),
+    #[error("schema {0} not found")]
+    NotFound(String),
+    #[error("version {version} of schema {name} not found")]
+    VersionNotFound { name: String, version: u32 },
+    #[error("schema {name} has errors")]
+    Lint { name: String, issues: Vec<Issue> },
+    #[error("schema {name} does not satisfy the {mode:?} compatibility with version {version}")]
+    Incompatible {
+        name: String,
+        version: u32,
+        mode: CompatibilityMode,
+        report: CompatibilityReport,
+    },
+    #[error("failed to access the registry: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid schema in the registry: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Stores the versions of the schemas by name, the versions start at 1.
+pub trait SchemaRegistry {
+    /// The schema is rejected if it has lint errors, or is incompatible with the latest version.
+    fn register(&mut self, name: &str, schema: FieldEnum) -> Result<u32, RegistryError>;
+
+    fn get(&self, name: &str, version: u32) -> Result<FieldEnum, RegistryError>;
+
+    fn latest(&self, name: &str) -> Result<(u32, FieldEnum), RegistryError>;
+
+    /// E.g. to roll back a release, the next registration still takes the version after the last.
+    fn set_latest(&mut self, name: &str, version: u32) -> Result<(), RegistryError>;
+
+    fn versions(&self, name: &str) -> Result<Vec<u32>, RegistryError>;
+
+    fn names(&self) -> Result<Vec<String>, RegistryError>;
+}
+
+/// Only ASCII letters, digits, `-`, `_` and `.` are allowed, without a leading `.`.
+pub(crate) fn check_name(name: &str) -> Result<(), RegistryError> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(RegistryError::InvalidName(name.to_string()))
+    }
+}
+
+pub(crate) fn admit(
+    name: &str,
+    schema: &FieldEnum,
+    latest: Option<(u32, &FieldEnum)>,
+    mode: CompatibilityMode,
+) -> Result<(), RegistryError> {
+    check_name(name)?;
+    lint::check(schema).map_err(|issues| RegistryError::Lint {
+        name: name.to_string(),
+        issues,
+    })?;
+    if let Some((version, old)) = latest {
+        let report = check(old, schema);
+        if !report.satisfies(mode) {
+            return Err(RegistryError::Incompatible {
+                name: name.to_string(),
+                version,
+                mode,
+                report,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::registry::{RegistryError, SchemaRegistry};
+    use serde_json::json;
+
+    pub(crate) fn client_schema(max_length: u64, required: &[&str]) -> FieldEnum {
+        serde_json::from_value(json!({
+            "type": "object",
+            "name": "client",
+            "properties": {
+                "name": {"type": "string", "name": "name", "maxLength": max_length},
+                "email": {"type": "email", "name": "email"}
+            },
+            "required": required
+        }))
+        .unwrap()
+    }
+
+    fn json(schema: FieldEnum) -> serde_json::Value {
+        serde_json::to_value(schema).unwrap()
+    }
+
+    /// Exercises a registry with the backward compatibility mode.
+    pub(crate) fn exercise(registry: &mut impl SchemaRegistry) {
+        assert!(matches!(
+            registry.latest("client"),
+            Err(RegistryError::NotFound(_))
+        ));
+        assert!(matches!(
+            registry.register("../client", client_schema(32, &[])),
+            Err(RegistryError::InvalidName(_))
+        ));
+
+        assert_eq!(
+            registry
+                .register("client", client_schema(32, &["name"]))
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            registry.register("client", client_schema(64, &[])).unwrap(),
+            2
+        );
+        assert_eq!(registry.register("user", client_schema(8, &[])).unwrap(), 1);
+
+        let Err(RegistryError::Incompatible {
+            version, report, ..
+        }) = registry.register("client", client_schema(16, &["email"]))
+        else {
+            panic!("incompatible schema is expected")
+        };
+        assert_eq!(version, 2);
+        assert_eq!(report.backward_reasons.len(), 2);
+
+        let invalid: FieldEnum = serde_json::from_value(json!({
+            "type": "string",
+            "name": "client",
+            "minLength": 8,
+            "maxLength": 4
+        }))
+        .unwrap();
+        assert!(matches!(
+            registry.register("client", invalid),
+            Err(RegistryError::Lint { .. })
+        ));
+
+        assert_eq!(registry.versions("client").unwrap(), vec![1, 2]);
+        assert_eq!(registry.names().unwrap(), vec!["client", "user"]);
+        assert_eq!(
+            json(registry.get("client", 1).unwrap()),
+            json(client_schema(32, &["name"]))
+        );
+        assert!(matches!(
+            registry.get("client", 3),
+            Err(RegistryError::VersionNotFound { version: 3, .. })
+        ));
+        let (version, latest) = registry.latest("client").unwrap();
+        assert_eq!((version, json(latest)), (2, json(client_schema(64, &[]))));
+
+        registry.set_latest("client", 1).unwrap();
+        assert_eq!(registry.latest("client").unwrap().0, 1);
+        assert!(registry.set_latest("client", 5).is_err());
+        assert_eq!(
+            registry.register("client", client_schema(48, &[])).unwrap(),
+            3
+        );
+        let (version, latest) = registry.latest("client").unwrap();
+        assert_eq!((version, json(latest)), (3, json(client_schema(48, &[]))));
+    }
+}
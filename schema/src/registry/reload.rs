@@ -0,0 +1,335 @@
+use crate::core::field::FieldEnum;
+use crate::registry::{RegistryError, SchemaRegistry};
+use crate::visitor::lint;
+use crate::visitor::validator::{Validator, ValidatorOptions};
+use arc_swap::ArcSwap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::fs;
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+/// A shared handle of the compiled validators by name, a replaced validator is swapped
+/// atomically while the validations holding the old one finish on it.
+#[derive(Debug, Clone)]
+pub struct Validators {
+    validators: Arc<ArcSwap<BTreeMap<String, Arc<Validator>>>>,
+    options: ValidatorOptions,
+}
+
+impl Default for Validators {
+    fn default() -> Self {
+        Validators::with_options(ValidatorOptions::default())
+    }
+}
+
+impl Validators {
+    pub fn new() -> Self {
+        Validators::default()
+    }
+
+    pub fn with_options(options: ValidatorOptions) -> Self {
+        Validators {
+            validators: Arc::new(ArcSwap::from_pointee(BTreeMap::new())),
+            options,
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<Validator>> {
+        self.validators.load().get(name).cloned()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.validators.load().keys().cloned().collect()
+    }
+
+    pub fn insert(&self, name: &str, schema: impl Into<FieldEnum>) {
+        let validator = Arc::new(Validator::with_options(schema, self.options.clone()));
+        self.validators.rcu(|validators| {
+            let mut validators = BTreeMap::clone(validators);
+            validators.insert(name.to_string(), validator.clone());
+            validators
+        });
+    }
+
+    pub fn remove(&self, name: &str) {
+        self.validators.rcu(|validators| {
+            let mut validators = BTreeMap::clone(validators);
+            validators.remove(name);
+            validators
+        });
+    }
+
+    /// The validator is kept as is if the file can not be read, or the schema has lint errors.
+    pub fn load_file(&self, name: &str, path: impl AsRef<Path>) -> Result<(), RegistryError> {
+        self.load(name, &fs::read(path)?)
+    }
+
+    fn load(&self, name: &str, document: &[u8]) -> Result<(), RegistryError> {
+        let schema: FieldEnum = serde_json::from_slice(document)?;
+        lint::check(&schema).map_err(|issues| RegistryError::Lint {
+            name: name.to_string(),
+            issues,
+        })?;
+        self.insert(name, schema);
+        Ok(())
+    }
+
+    pub fn load_latest(
+        &self,
+        registry: &impl SchemaRegistry,
+        name: &str,
+    ) -> Result<u32, RegistryError> {
+        let (version, schema) = registry.latest(name)?;
+        self.insert(name, schema);
+        Ok(version)
+    }
+}
+
+/// The hash tells apart the files rewritten within the resolution of the modification time.
+type Stamp = (Option<SystemTime>, u64, u64);
+
+/// Reads the file once, so the schema loaded is the one the stamp was taken of.
+fn read(path: &Path) -> Result<(Stamp, Vec<u8>), RegistryError> {
+    let modified = fs::metadata(path)?.modified().ok();
+    let document = fs::read(path)?;
+    let mut hasher = DefaultHasher::new();
+    hasher.write(&document);
+    let stamp = (modified, document.len() as u64, hasher.finish());
+    Ok((stamp, document))
+}
+
+#[derive(Debug)]
+struct WatchedFile {
+    name: String,
+    path: PathBuf,
+    stamp: Option<Stamp>,
+}
+
+/// Reloads the validators when their schema files change, by polling the files.
+#[derive(Debug)]
+pub struct FileWatcher {
+    validators: Validators,
+    files: Vec<WatchedFile>,
+}
+
+impl FileWatcher {
+    pub fn new(validators: Validators) -> Self {
+        FileWatcher {
+            validators,
+            files: vec![],
+        }
+    }
+
+    /// The file is still watched if it fails to load, so it is loaded once it is fixed.
+    pub fn watch(&mut self, name: &str, path: impl AsRef<Path>) -> Result<(), RegistryError> {
+        let path = path.as_ref().to_path_buf();
+        let (stamp, loaded) = match read(&path) {
+            Ok((stamp, document)) => (Some(stamp), self.validators.load(name, &document)),
+            Err(e) => (None, Err(e)),
+        };
+        self.files.push(WatchedFile {
+            name: name.to_string(),
+            path,
+            stamp,
+        });
+        loaded
+    }
+
+    /// The old validator is kept for the files which fail to reload.
+    pub fn poll(&mut self) -> Vec<(String, Result<(), RegistryError>)> {
+        let mut reloaded = vec![];
+        for file in &mut self.files {
+            let (stamp, document) = match read(&file.path) {
+                Ok(read) => read,
+                // The file may be removed for a moment while it is being replaced.
+                Err(_) => continue,
+            };
+            if file.stamp == Some(stamp) {
+                continue;
+            }
+            file.stamp = Some(stamp);
+            let result = self.validators.load(&file.name, &document);
+            reloaded.push((file.name.clone(), result));
+        }
+        reloaded
+    }
+
+    /// Polls in a background thread until the returned handle is stopped or dropped.
+    pub fn spawn<F>(mut self, interval: Duration, on_error: F) -> Watching
+    where
+        F: Fn(&str, RegistryError) + Send + 'static,
+    {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stopped = stopped.clone();
+            thread::spawn(move || loop {
+                thread::park_timeout(interval);
+                if stopped.load(Ordering::Relaxed) {
+                    break;
+                }
+                for (name, result) in self.poll() {
+                    if let Err(e) = result {
+                        on_error(&name, e);
+                    }
+                }
+            })
+        };
+        Watching {
+            stopped,
+            thread: Some(thread),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Watching {
+    stopped: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Watching {
+    pub fn stop(mut self) {
+        self.join();
+    }
+
+    fn join(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for Watching {
+    fn drop(&mut self) {
+        self.join();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::value::FieldValue;
+    use crate::registry::memory::MemoryRegistry;
+    use crate::registry::reload::{FileWatcher, Validators};
+    use crate::registry::tests::client_schema;
+    use crate::registry::{RegistryError, SchemaRegistry};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::time::{Duration, Instant};
+
+    fn client(name: &str) -> FieldValue {
+        FieldValue::Object(BTreeMap::from([(
+            "name".to_string(),
+            FieldValue::String(name.to_string()),
+        )]))
+    }
+
+    #[test]
+    fn test_replace() {
+        let validators = Validators::new();
+        assert!(validators.get("client").is_none());
+        validators.insert("client", client_schema(8, &[]));
+
+        let shared = validators.clone();
+        let in_flight = shared.get("client").unwrap();
+        validators.insert("client", client_schema(4, &[]));
+        assert!(in_flight.validate(&client("Robert")).is_ok());
+        assert!(shared
+            .get("client")
+            .unwrap()
+            .validate(&client("Robert"))
+            .is_err());
+
+        validators.remove("client");
+        assert!(shared.names().is_empty());
+    }
+
+    #[test]
+    fn test_load_latest() {
+        let mut registry = MemoryRegistry::new();
+        registry.register("client", client_schema(8, &[])).unwrap();
+        registry.register("client", client_schema(4, &[])).unwrap();
+        let validators = Validators::new();
+        assert_eq!(validators.load_latest(&registry, "client").unwrap(), 2);
+        assert!(!validators
+            .get("client")
+            .unwrap()
+            .is_valid(&client("Robert")));
+        assert!(matches!(
+            validators.load_latest(&registry, "user"),
+            Err(RegistryError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_watch() {
+        let root = std::env::temp_dir().join(format!("avocado-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&root).unwrap();
+        let path = root.join("client.json");
+        let write = |max_length: u64| {
+            fs::write(
+                &path,
+                serde_json::to_vec(&client_schema(max_length, &[])).unwrap(),
+            )
+            .unwrap()
+        };
+        write(8);
+
+        let validators = Validators::new();
+        let mut watcher = FileWatcher::new(validators.clone());
+        watcher.watch("client", &path).unwrap();
+        assert!(watcher.poll().is_empty());
+        assert!(validators
+            .get("client")
+            .unwrap()
+            .is_valid(&client("Robert")));
+
+        fs::write(&path, json!({"type": "string"}).to_string()).unwrap();
+        let reloaded = watcher.poll();
+        assert_eq!(reloaded.len(), 1);
+        assert!(matches!(reloaded[0].1, Err(RegistryError::Json(_))));
+        assert!(validators
+            .get("client")
+            .unwrap()
+            .is_valid(&client("Robert")));
+
+        write(8);
+        assert_eq!(watcher.poll().len(), 1);
+        let modified = fs::metadata(&path).unwrap().modified().unwrap();
+        write(4);
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        assert_eq!(watcher.poll().len(), 1);
+        assert!(!validators
+            .get("client")
+            .unwrap()
+            .is_valid(&client("Robert")));
+
+        let watching = watcher.spawn(Duration::from_millis(10), |_, _| {});
+        write(8);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !validators
+            .get("client")
+            .unwrap()
+            .is_valid(&client("Robert"))
+        {
+            assert!(Instant::now() < deadline, "schema file is not reloaded");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        watching.stop();
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,305 @@
+use crate::core::field::{FieldEnum, FieldType};
+use crate::core::value::FieldValue;
+use crate::visitor::validator::ValidationError;
+use crate::visitor::walk::{walk_value, walk_with_value, Path, ValueVisitor};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use email_address_parser::EmailAddress;
+use std::collections::BTreeMap;
+
+fn convert(field: &FieldEnum, s: &str) -> Option<Result<FieldValue, FieldType>> {
+    let converted = match field {
+        FieldEnum::Integer(_) => s
+            .trim()
+            .parse()
+            .map(FieldValue::Integer)
+            .map_err(|_| FieldType::Integer),
+        FieldEnum::UInteger(_) => s
+            .trim()
+            .parse()
+            .map(FieldValue::UInteger)
+            .map_err(|_| FieldType::UInteger),
+        FieldEnum::Float(_) => s
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .map(FieldValue::Float)
+            .ok_or(FieldType::Float),
+        FieldEnum::Boolean(_) => match s.trim() {
+            "true" => Ok(FieldValue::Boolean(true)),
+            "false" => Ok(FieldValue::Boolean(false)),
+            _ => Err(FieldType::Boolean),
+        },
+        // the `datetime-local` inputs of HTML forms send no offset, read as UTC
+        FieldEnum::Datetime(_) => DateTime::parse_from_rfc3339(s.trim())
+            .map(|v| v.with_timezone(&Utc))
+            .or_else(|_| {
+                NaiveDateTime::parse_from_str(s.trim(), "%Y-%m-%dT%H:%M:%S").map(|v| v.and_utc())
+            })
+            .or_else(|_| {
+                NaiveDateTime::parse_from_str(s.trim(), "%Y-%m-%dT%H:%M").map(|v| v.and_utc())
+            })
+            .map(FieldValue::DateTime)
+            .map_err(|_| FieldType::DateTime),
+        FieldEnum::Date(_) => s
+            .trim()
+            .parse::<NaiveDate>()
+            .map(FieldValue::Date)
+            .map_err(|_| FieldType::Date),
+        FieldEnum::Time(_) => s
+            .trim()
+            .parse::<NaiveTime>()
+            .map(FieldValue::Time)
+            .map_err(|_| FieldType::Time),
+        FieldEnum::Email(_) => EmailAddress::parse(s.trim(), None)
+            .map(FieldValue::Email)
+            .ok_or(FieldType::Email),
+        _ => return None,
+    };
+    Some(converted)
+}
+
+struct Coercion {
+    errors: BTreeMap<String, Vec<ValidationError>>,
+}
+
+impl ValueVisitor for Coercion {
+    fn visit_value(&mut self, field: &FieldEnum, value: &mut FieldValue, path: &Path) {
+        if let FieldValue::String(s) = value {
+            match convert(field, s) {
+                Some(Ok(converted)) => *value = converted,
+                Some(Err(typed)) => {
+                    let error = ValidationError::new(
+                        "Coercion",
+                        format!(
+                            "value {} can not be converted to {} ({})",
+                            s, typed, "Coercion"
+                        ),
+                    );
+                    self.errors.entry(path.to_string()).or_default().push(error);
+                }
+                None => {}
+            }
+        }
+        walk_value(self, field, value, path)
+    }
+}
+
+/// Converts the strings in the value (e.g. from HTML forms) into the types of their fields, the
+/// errors are located by the path of the value, e.g. `client/tags/0`.
+pub fn coerce(
+    schema: &FieldEnum,
+    value: &FieldValue,
+) -> Result<FieldValue, BTreeMap<String, Vec<ValidationError>>> {
+    let mut value = value.clone();
+    let mut coercion = Coercion {
+        errors: BTreeMap::new(),
+    };
+    walk_with_value(&mut coercion, schema, &mut value);
+    if coercion.errors.is_empty() {
+        Ok(value)
+    } else {
+        Err(coercion.errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::core::value::FieldValue;
+    use crate::visitor::coerce::{coerce, Coercion};
+    use crate::visitor::form::render;
+    use crate::visitor::validator::Validator;
+    use crate::visitor::walk::ValueVisitor;
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+    use std::collections::BTreeMap;
+
+    fn client_schema() -> FieldEnum {
+        serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "client",
+                "properties": {
+                    "age": {"type": "uinteger", "name": "age", "maximum": 200},
+                    "balance": {"type": "integer", "name": "balance"},
+                    "ratio": {"type": "float", "name": "ratio"},
+                    "married": {"type": "boolean", "name": "married"},
+                    "birthday": {"type": "date", "name": "birthday"},
+                    "wakeup": {"type": "time", "name": "wakeup"},
+                    "updated": {"type": "datetime", "name": "updated"},
+                    "email": {"type": "email", "name": "email"},
+                    "nickname": {"type": "string", "name": "nickname"},
+                    "scores": {
+                        "type": "array",
+                        "name": "scores",
+                        "item": {"type": "uinteger", "name": "score"}
+                    }
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn form(fields: &[(&str, FieldValue)]) -> FieldValue {
+        FieldValue::Object(
+            fields
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect(),
+        )
+    }
+
+    fn string(s: &str) -> FieldValue {
+        FieldValue::String(s.to_string())
+    }
+
+    #[test]
+    fn test_coerce() {
+        let schema = client_schema();
+        let value = coerce(
+            &schema,
+            &form(&[
+                ("age", string("32")),
+                ("balance", string("-100")),
+                ("ratio", string("0.5")),
+                ("married", string("true")),
+                ("birthday", string("1991-09-19")),
+                ("wakeup", string("07:30:00")),
+                ("updated", string("2023-09-19T07:30:00Z")),
+                ("email", string("admin@avocado.com")),
+                ("nickname", string("42")),
+                (
+                    "scores",
+                    FieldValue::Array(vec![string("1"), FieldValue::UInteger(2)]),
+                ),
+            ]),
+        )
+        .unwrap();
+        let FieldValue::Object(o) = &value else {
+            panic!("object is expected")
+        };
+        assert_eq!(o["age"], FieldValue::UInteger(32));
+        assert_eq!(o["balance"], FieldValue::Integer(-100));
+        assert_eq!(o["ratio"], FieldValue::Float(0.5));
+        assert_eq!(o["married"], FieldValue::Boolean(true));
+        assert_eq!(
+            o["birthday"],
+            FieldValue::Date(NaiveDate::from_ymd_opt(1991, 9, 19).unwrap())
+        );
+        assert_eq!(
+            o["wakeup"],
+            FieldValue::Time(NaiveTime::from_hms_opt(7, 30, 0).unwrap())
+        );
+        assert!(matches!(o["updated"], FieldValue::DateTime(_)));
+        assert!(matches!(o["email"], FieldValue::Email(_)));
+        assert_eq!(o["nickname"], string("42"));
+        assert_eq!(
+            o["scores"],
+            FieldValue::Array(vec![FieldValue::UInteger(1), FieldValue::UInteger(2)])
+        );
+        assert!(Validator::new(schema).validate(&value).is_ok());
+    }
+
+    #[test]
+    fn test_form_datetime() {
+        let schema = client_schema();
+        let updated = Utc.with_ymd_and_hms(2023, 9, 19, 7, 30, 0).unwrap();
+        let value = form(&[("updated", FieldValue::DateTime(updated))]);
+        let html = render(&schema, Some(&value), None);
+        let input = html
+            .split("name=\"client/updated\"")
+            .nth(1)
+            .and_then(|s| s.split("value=\"").nth(1))
+            .and_then(|s| s.split('"').next())
+            .unwrap();
+        assert_eq!(
+            coerce(&schema, &form(&[("updated", string(input))])).unwrap(),
+            value
+        );
+        assert_eq!(
+            coerce(&schema, &form(&[("updated", string("2023-09-19T07:30"))])).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let errors = coerce(
+            &client_schema(),
+            &form(&[
+                ("age", string("-1")),
+                ("married", string("yes")),
+                (
+                    "scores",
+                    FieldValue::Array(vec![string("1"), string("two")]),
+                ),
+            ]),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            errors
+                .iter()
+                .map(|(k, v)| (k.as_str(), v[0].to_string()))
+                .collect::<BTreeMap<_, _>>(),
+            BTreeMap::from([
+                (
+                    "client/age",
+                    "value -1 can not be converted to unsigned integer (Coercion)".to_string()
+                ),
+                (
+                    "client/married",
+                    "value yes can not be converted to boolean (Coercion)".to_string()
+                ),
+                (
+                    "client/scores/1",
+                    "value two can not be converted to unsigned integer (Coercion)".to_string()
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_non_finite_float() {
+        for ratio in ["NaN", "inf", "-infinity"] {
+            let errors = coerce(&client_schema(), &form(&[("ratio", string(ratio))]))
+                .err()
+                .unwrap();
+            assert_eq!(
+                errors["client/ratio"][0].to_string(),
+                format!("value {ratio} can not be converted to float (Coercion)")
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let schema: FieldEnum = serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "node",
+                "properties": {
+                    "age": {"type": "uinteger", "name": "age"},
+                    "child": {"type": "reference", "name": "child", "reference": "node"}
+                }
+            }"#,
+        )
+        .unwrap();
+        let mut value = form(&[("age", string("x"))]);
+        for _ in 1..100 {
+            value = form(&[("age", string("x")), ("child", value)]);
+        }
+
+        let max_depth = Coercion {
+            errors: BTreeMap::new(),
+        }
+        .max_depth();
+        let errors = coerce(&schema, &value).err().unwrap();
+        assert_eq!(errors.len(), max_depth - 1);
+        assert!(errors
+            .keys()
+            .all(|path| path.split('/').count() <= max_depth));
+    }
+}
@@ -0,0 +1,269 @@
+use crate::core::field::FieldEnum;
+use crate::visitor::diff::{diff, Change, Difference, Direction};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compatibility {
+    Full,
+    /// The data valid under the old schema remains valid under the new one.
+    Backward,
+    /// The data valid under the new schema is also valid under the old one.
+    Forward,
+    Breaking,
+}
+
+impl Display for Compatibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Compatibility::Full => write!(f, "fully compatible"),
+            Compatibility::Backward => write!(f, "backward compatible"),
+            Compatibility::Forward => write!(f, "forward compatible"),
+            Compatibility::Breaking => write!(f, "breaking"),
+        }
+    }
+}
+
+/// The compatibility required of a new version, as in the Avro and Protobuf registries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompatibilityMode {
+    #[default]
+    None,
+    Backward,
+    Forward,
+    Full,
+}
+
+fn breaks_backward(change: &Change) -> bool {
+    match change {
+        Change::Constraint { direction, .. } => *direction != Direction::Loosened,
+        Change::PropertyAdded { .. } | Change::ItemAdded | Change::AdditionalPropertiesAdded => {
+            true
+        }
+        // the old value of the property may be rejected by the additional properties
+        Change::PropertyRemoved {
+            additional_properties,
+        } => *additional_properties,
+        Change::ItemRemoved | Change::AdditionalPropertiesRemoved => false,
+        Change::TypeChanged { .. } | Change::ReferenceChanged { .. } => true,
+        Change::RequiredAdded(_) | Change::EnumValueRemoved(_) => true,
+        Change::RequiredRemoved(_) | Change::EnumValueAdded(_) => false,
+    }
+}
+
+fn breaks_forward(change: &Change) -> bool {
+    match change {
+        Change::Constraint { direction, .. } => *direction != Direction::Tightened,
+        // the new value of the property may be rejected by the additional properties
+        Change::PropertyAdded {
+            additional_properties,
+        } => *additional_properties,
+        Change::ItemAdded | Change::AdditionalPropertiesAdded => false,
+        Change::PropertyRemoved { .. }
+        | Change::ItemRemoved
+        | Change::AdditionalPropertiesRemoved => true,
+        Change::TypeChanged { .. } | Change::ReferenceChanged { .. } => true,
+        Change::RequiredAdded(_) | Change::EnumValueRemoved(_) => false,
+        Change::RequiredRemoved(_) | Change::EnumValueAdded(_) => true,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompatibilityReport {
+    pub compatibility: Compatibility,
+    pub backward_reasons: Vec<Difference>,
+    pub forward_reasons: Vec<Difference>,
+}
+
+impl CompatibilityReport {
+    pub fn is_backward(&self) -> bool {
+        self.backward_reasons.is_empty()
+    }
+
+    pub fn is_forward(&self) -> bool {
+        self.forward_reasons.is_empty()
+    }
+
+    pub fn satisfies(&self, mode: CompatibilityMode) -> bool {
+        match mode {
+            CompatibilityMode::None => true,
+            CompatibilityMode::Backward => self.is_backward(),
+            CompatibilityMode::Forward => self.is_forward(),
+            CompatibilityMode::Full => self.is_backward() && self.is_forward(),
+        }
+    }
+}
+
+impl Display for CompatibilityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.compatibility)?;
+        for reason in &self.backward_reasons {
+            writeln!(f, "  not backward compatible: {}", reason)?;
+        }
+        for reason in &self.forward_reasons {
+            writeln!(f, "  not forward compatible: {}", reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Classifies the change from the old version of a schema to the new one. Since the properties
+/// not in a schema are not checked, an added property breaks backward compatibility and a
+/// removed one breaks forward compatibility. Where the other version validates the property by
+/// its additional properties instead, it breaks the other direction too.
+pub fn check(old: &FieldEnum, new: &FieldEnum) -> CompatibilityReport {
+    let differences = diff(old, new).differences;
+    let backward_reasons: Vec<Difference> = differences
+        .iter()
+        .filter(|d| breaks_backward(&d.change))
+        .cloned()
+        .collect();
+    let forward_reasons: Vec<Difference> = differences
+        .into_iter()
+        .filter(|d| breaks_forward(&d.change))
+        .collect();
+    let compatibility = match (backward_reasons.is_empty(), forward_reasons.is_empty()) {
+        (true, true) => Compatibility::Full,
+        (true, false) => Compatibility::Backward,
+        (false, true) => Compatibility::Forward,
+        (false, false) => Compatibility::Breaking,
+    };
+    CompatibilityReport {
+        compatibility,
+        backward_reasons,
+        forward_reasons,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::visitor::compatibility::{check, Compatibility, CompatibilityMode};
+    use crate::visitor::diff::Difference;
+    use serde_json::json;
+
+    fn client_schema(max_length: u64, levels: &[&str], required: &[&str]) -> FieldEnum {
+        serde_json::from_value(json!({
+            "type": "object",
+            "name": "client",
+            "properties": {
+                "name": {"type": "string", "name": "name", "maxLength": max_length},
+                "level": {"type": "string", "name": "level", "enum": levels},
+                "email": {"type": "email", "name": "email"}
+            },
+            "required": required
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_full() {
+        let old = client_schema(32, &["basic"], &["name"]);
+        let report = check(&old, &client_schema(32, &["basic"], &["name"]));
+        assert_eq!(report.compatibility, Compatibility::Full);
+        assert!(report.satisfies(CompatibilityMode::Full));
+        assert_eq!(report.to_string(), "fully compatible\n");
+    }
+
+    #[test]
+    fn test_backward() {
+        let old = client_schema(32, &["basic"], &["name", "email"]);
+        let report = check(&old, &client_schema(64, &["basic", "premium"], &["name"]));
+        assert_eq!(report.compatibility, Compatibility::Backward);
+        assert!(report.satisfies(CompatibilityMode::Backward));
+        assert!(!report.satisfies(CompatibilityMode::Forward));
+        assert!(!report.satisfies(CompatibilityMode::Full));
+        assert!(report.satisfies(CompatibilityMode::None));
+        assert_eq!(report.forward_reasons.len(), 3);
+        assert_eq!(
+            report.to_string(),
+            "backward compatible\n\
+             \x20 not forward compatible: client: required property email removed\n\
+             \x20 not forward compatible: client/level: enum value \"premium\" added\n\
+             \x20 not forward compatible: client/name: maxLength loosened from 32 to 64\n"
+        );
+    }
+
+    #[test]
+    fn test_forward() {
+        let old = client_schema(64, &["basic", "premium"], &["name"]);
+        let report = check(&old, &client_schema(32, &["basic"], &["name", "email"]));
+        assert_eq!(report.compatibility, Compatibility::Forward);
+        assert_eq!(report.backward_reasons.len(), 3);
+        assert!(report.satisfies(CompatibilityMode::Forward));
+        assert!(!report.satisfies(CompatibilityMode::Backward));
+    }
+
+    #[test]
+    fn test_breaking() {
+        let old = client_schema(64, &["basic"], &["name"]);
+        let report = check(&old, &client_schema(32, &["basic", "premium"], &["name"]));
+        assert_eq!(report.compatibility, Compatibility::Breaking);
+        assert_eq!(
+            report
+                .backward_reasons
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>(),
+            vec!["client/name: maxLength tightened from 64 to 32"]
+        );
+        assert_eq!(
+            report
+                .forward_reasons
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>(),
+            vec!["client/level: enum value \"premium\" added"]
+        );
+
+        let typed: FieldEnum =
+            serde_json::from_value(json!({"type": "integer", "name": "client"})).unwrap();
+        assert_eq!(check(&old, &typed).compatibility, Compatibility::Breaking);
+    }
+
+    #[test]
+    fn test_property() {
+        let object = |properties: serde_json::Value, additional: bool| -> FieldEnum {
+            let mut object = json!({"type": "object", "name": "client", "properties": properties});
+            if additional {
+                object["additionalProperties"] = json!({"type": "string", "name": "label"});
+            }
+            serde_json::from_value(object).unwrap()
+        };
+        let name = json!({"name": {"type": "string", "name": "name"}});
+        let age = json!({
+            "name": {"type": "string", "name": "name"},
+            "age": {"type": "uinteger", "name": "age"}
+        });
+        let reasons = |reasons: &[Difference]| -> Vec<String> {
+            reasons.iter().map(|r| r.to_string()).collect()
+        };
+
+        let added = check(&object(name.clone(), false), &object(age.clone(), false));
+        assert_eq!(added.compatibility, Compatibility::Forward);
+        assert_eq!(
+            reasons(&added.backward_reasons),
+            vec!["client/age: property added"]
+        );
+        let removed = check(&object(age.clone(), false), &object(name.clone(), false));
+        assert_eq!(removed.compatibility, Compatibility::Backward);
+        assert_eq!(
+            reasons(&removed.forward_reasons),
+            vec!["client/age: property removed"]
+        );
+
+        // on the other side, the age is an additional property which must be a string
+        let added = check(&object(name.clone(), true), &object(age.clone(), true));
+        assert_eq!(added.compatibility, Compatibility::Breaking);
+        assert_eq!(
+            reasons(&added.forward_reasons),
+            vec!["client/age: property added"]
+        );
+        let removed = check(&object(age, true), &object(name, true));
+        assert_eq!(removed.compatibility, Compatibility::Breaking);
+        assert_eq!(
+            reasons(&removed.backward_reasons),
+            vec!["client/age: property removed"]
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::core::field::object::ObjectField;
+use crate::core::field::FieldEnum;
+use crate::core::value::FieldValue;
+use crate::visitor::json::from_json;
+use crate::visitor::walk::{walk_object_value, walk_with_value, Path, ValueVisitor};
+
+struct Defaults;
+
+impl ValueVisitor for Defaults {
+    fn visit_object(&mut self, field: &ObjectField, value: &mut FieldValue, path: &Path) {
+        if let FieldValue::Object(values) = value {
+            let required = field.required.as_deref().unwrap_or_default();
+            for (key, property) in &field.properties {
+                let missing = matches!(values.get(key), None | Some(FieldValue::Null));
+                if missing && !required.contains(key) {
+                    let default = path.resolve(property).and_then(|p| {
+                        let default = property.metadata().default.as_ref();
+                        Some(from_json(p, default.or(p.metadata().default.as_ref())?))
+                    });
+                    if let Some(default) = default {
+                        values.insert(key.clone(), default);
+                    }
+                }
+            }
+        }
+        walk_object_value(self, field, value, path)
+    }
+}
+
+/// Returns a copy of the value with the missing (or null) optional properties set to their
+/// defaults.
+pub fn fill_defaults(schema: &FieldEnum, value: &FieldValue) -> FieldValue {
+    let mut value = value.clone();
+    walk_with_value(&mut Defaults, schema, &mut value);
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::core::value::FieldValue;
+    use crate::visitor::default::{fill_defaults, Defaults};
+    use crate::visitor::json::from_json;
+    use crate::visitor::walk::ValueVisitor;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    fn config_schema() -> FieldEnum {
+        serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "config",
+                "properties": {
+                    "host": {
+                        "type": "string",
+                        "name": "host",
+                        "default": "localhost"
+                    },
+                    "port": {
+                        "type": "uinteger",
+                        "name": "port",
+                        "default": 8080
+                    },
+                    "name": {
+                        "type": "string",
+                        "name": "name",
+                        "default": "server"
+                    },
+                    "tls": {
+                        "type": "object",
+                        "name": "tls",
+                        "properties": {
+                            "enabled": {
+                                "type": "boolean",
+                                "name": "enabled",
+                                "default": false
+                            }
+                        },
+                        "default": {}
+                    },
+                    "upstreams": {
+                        "type": "array",
+                        "name": "upstreams",
+                        "item": {
+                            "type": "object",
+                            "name": "upstream",
+                            "properties": {
+                                "weight": {
+                                    "type": "uinteger",
+                                    "name": "weight",
+                                    "default": 1
+                                },
+                                "upstreams": {
+                                    "type": "reference",
+                                    "name": "children",
+                                    "reference": "upstreams"
+                                }
+                            }
+                        }
+                    }
+                },
+                "required": ["name"]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fill_defaults() {
+        let schema = config_schema();
+        let value = from_json(
+            &schema,
+            &json!({
+                "host": null,
+                "port": 443,
+                "upstreams": [
+                    {"weight": 2, "upstreams": [{}]},
+                    {}
+                ]
+            }),
+        );
+        let expected = from_json(
+            &schema,
+            &json!({
+                "host": "localhost",
+                "port": 443,
+                "tls": {"enabled": false},
+                "upstreams": [
+                    {"weight": 2, "upstreams": [{"weight": 1}]},
+                    {"weight": 1}
+                ]
+            }),
+        );
+        assert_eq!(fill_defaults(&schema, &value), expected);
+    }
+
+    #[test]
+    fn test_typed_default() {
+        let schema = config_schema();
+        let FieldValue::Object(o) = fill_defaults(&schema, &from_json(&schema, &json!({}))) else {
+            panic!("object is expected")
+        };
+        assert_eq!(o["port"], FieldValue::UInteger(8080));
+        assert!(!o.contains_key("name"));
+    }
+
+    #[test]
+    fn test_reference_default() {
+        let schema: FieldEnum = serde_json::from_value(json!({
+            "type": "array",
+            "name": "categories",
+            "item": {
+                "type": "object",
+                "name": "category",
+                "properties": {
+                    "name": {"type": "string", "name": "name"},
+                    "children": {
+                        "type": "reference",
+                        "name": "children",
+                        "reference": "categories",
+                        "default": []
+                    }
+                }
+            }
+        }))
+        .unwrap();
+        let value = from_json(&schema, &json!([{"name": "books", "children": [{}]}]));
+        assert_eq!(
+            fill_defaults(&schema, &value),
+            from_json(
+                &schema,
+                &json!([{"name": "books", "children": [{"children": []}]}])
+            )
+        );
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let schema: FieldEnum = serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "node",
+                "properties": {
+                    "size": {"type": "uinteger", "name": "size", "default": 1},
+                    "child": {"type": "reference", "name": "child", "reference": "node"}
+                }
+            }"#,
+        )
+        .unwrap();
+        let mut value = FieldValue::Object(Default::default());
+        for _ in 1..100 {
+            value = FieldValue::Object(BTreeMap::from([("child".to_string(), value)]));
+        }
+
+        let mut value = &fill_defaults(&schema, &value);
+        let mut filled = 0;
+        while let FieldValue::Object(o) = value {
+            if o.contains_key("size") {
+                filled += 1;
+            }
+            match o.get("child") {
+                Some(child) => value = child,
+                None => break,
+            }
+        }
+        assert_eq!(filled, Defaults.max_depth());
+    }
+}
@@ -0,0 +1,559 @@
+use crate::core::field::{FieldEnum, FieldType};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// Whether a changed constraint accepts fewer or more values than before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Tightened,
+    Loosened,
+    Changed,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Tightened => write!(f, "tightened"),
+            Direction::Loosened => write!(f, "loosened"),
+            Direction::Changed => write!(f, "changed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// Whether the old object had additional properties, which validated the property before.
+    PropertyAdded {
+        additional_properties: bool,
+    },
+    /// Whether the new object has additional properties, which validate the property now.
+    PropertyRemoved {
+        additional_properties: bool,
+    },
+    ItemAdded,
+    ItemRemoved,
+    AdditionalPropertiesAdded,
+    AdditionalPropertiesRemoved,
+    TypeChanged {
+        old: FieldType,
+        new: FieldType,
+    },
+    ReferenceChanged {
+        old: String,
+        new: String,
+    },
+    Constraint {
+        keyword: &'static str,
+        old: Option<Value>,
+        new: Option<Value>,
+        direction: Direction,
+    },
+    RequiredAdded(String),
+    RequiredRemoved(String),
+    EnumValueAdded(Value),
+    EnumValueRemoved(Value),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fn or_none(value: &Option<Value>) -> String {
+            value
+                .as_ref()
+                .map_or("none".to_string(), |value| value.to_string())
+        }
+
+        match self {
+            Change::PropertyAdded { .. } => write!(f, "property added"),
+            Change::PropertyRemoved { .. } => write!(f, "property removed"),
+            Change::ItemAdded => write!(f, "item added"),
+            Change::ItemRemoved => write!(f, "item removed"),
+            Change::AdditionalPropertiesAdded => write!(f, "additional properties added"),
+            Change::AdditionalPropertiesRemoved => write!(f, "additional properties removed"),
+            Change::TypeChanged { old, new } => {
+                write!(f, "type changed from {} to {}", old, new)
+            }
+            Change::ReferenceChanged { old, new } => {
+                write!(f, "reference changed from {} to {}", old, new)
+            }
+            Change::Constraint {
+                keyword,
+                old,
+                new,
+                direction,
+            } => write!(
+                f,
+                "{} {} from {} to {}",
+                keyword,
+                direction,
+                or_none(old),
+                or_none(new)
+            ),
+            Change::RequiredAdded(key) => write!(f, "required property {} added", key),
+            Change::RequiredRemoved(key) => write!(f, "required property {} removed", key),
+            Change::EnumValueAdded(value) => write!(f, "enum value {} added", value),
+            Change::EnumValueRemoved(value) => write!(f, "enum value {} removed", value),
+        }
+    }
+}
+
+/// A change located by the names of the fields joined by `/`, e.g. `client/tags/tag`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    pub path: String,
+    pub change: Change,
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.change)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Diff {
+    pub differences: Vec<Difference>,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl Display for Diff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for difference in &self.differences {
+            writeln!(f, "{}", difference)?;
+        }
+        Ok(())
+    }
+}
+
+struct State {
+    field_names: Vec<String>,
+    differences: Vec<Difference>,
+}
+
+impl State {
+    fn report(&mut self, change: Change) {
+        self.differences.push(Difference {
+            path: self.field_names.join("/"),
+            change,
+        })
+    }
+
+    fn report_child(&mut self, name: String, change: Change) {
+        self.field_names.push(name);
+        self.report(change);
+        self.field_names.pop();
+    }
+}
+
+fn value(value: impl Serialize) -> Option<Value> {
+    serde_json::to_value(value).ok()
+}
+
+fn bound<T: PartialOrd + Serialize>(
+    keyword: &'static str,
+    old: Option<T>,
+    new: Option<T>,
+    upper: bool,
+    state: &mut State,
+) {
+    let direction = match (&old, &new) {
+        (None, None) => return,
+        (Some(old), Some(new)) if old == new => return,
+        (None, Some(_)) => Direction::Tightened,
+        (Some(_), None) => Direction::Loosened,
+        (Some(old), Some(new)) if (new < old) == upper => Direction::Tightened,
+        (Some(_), Some(_)) => Direction::Loosened,
+    };
+    state.report(Change::Constraint {
+        keyword,
+        old: old.and_then(value),
+        new: new.and_then(value),
+        direction,
+    });
+}
+
+fn enumeration<T: PartialEq + Serialize>(
+    old: Option<&Vec<T>>,
+    new: Option<&Vec<T>>,
+    state: &mut State,
+) {
+    match (old, new) {
+        (None, None) => {}
+        (None, Some(new)) => state.report(Change::Constraint {
+            keyword: "enum",
+            old: None,
+            new: value(new),
+            direction: Direction::Tightened,
+        }),
+        (Some(old), None) => state.report(Change::Constraint {
+            keyword: "enum",
+            old: value(old),
+            new: None,
+            direction: Direction::Loosened,
+        }),
+        (Some(old), Some(new)) => {
+            for v in old.iter().filter(|v| !new.contains(v)) {
+                state.report(Change::EnumValueRemoved(value(v).unwrap_or_default()));
+            }
+            for v in new.iter().filter(|v| !old.contains(v)) {
+                state.report(Change::EnumValueAdded(value(v).unwrap_or_default()));
+            }
+        }
+    }
+}
+
+fn keyword<T: PartialEq + Serialize>(
+    keyword: &'static str,
+    old: Option<T>,
+    new: Option<T>,
+    state: &mut State,
+) {
+    let direction = match (&old, &new) {
+        (None, None) => return,
+        (Some(old), Some(new)) if old == new => return,
+        (None, Some(_)) => Direction::Tightened,
+        (Some(_), None) => Direction::Loosened,
+        (Some(_), Some(_)) => Direction::Changed,
+    };
+    state.report(Change::Constraint {
+        keyword,
+        old: old.and_then(value),
+        new: new.and_then(value),
+        direction,
+    });
+}
+
+fn diff_field(old: &FieldEnum, new: &FieldEnum, state: &mut State) {
+    match (old, new) {
+        (FieldEnum::Integer(old), FieldEnum::Integer(new)) => {
+            bound("minimum", old.minimum, new.minimum, false, state);
+            bound(
+                "exclusiveMinimum",
+                old.exclusive_minimum,
+                new.exclusive_minimum,
+                false,
+                state,
+            );
+            bound("maximum", old.maximum, new.maximum, true, state);
+            bound(
+                "exclusiveMaximum",
+                old.exclusive_maximum,
+                new.exclusive_maximum,
+                true,
+                state,
+            );
+            enumeration(old.enumeration.as_ref(), new.enumeration.as_ref(), state);
+        }
+        (FieldEnum::UInteger(old), FieldEnum::UInteger(new)) => {
+            bound("minimum", old.minimum, new.minimum, false, state);
+            bound(
+                "exclusiveMinimum",
+                old.exclusive_minimum,
+                new.exclusive_minimum,
+                false,
+                state,
+            );
+            bound("maximum", old.maximum, new.maximum, true, state);
+            bound(
+                "exclusiveMaximum",
+                old.exclusive_maximum,
+                new.exclusive_maximum,
+                true,
+                state,
+            );
+            enumeration(old.enumeration.as_ref(), new.enumeration.as_ref(), state);
+        }
+        (FieldEnum::Float(old), FieldEnum::Float(new)) => {
+            bound("minimum", old.minimum, new.minimum, false, state);
+            bound(
+                "exclusiveMinimum",
+                old.exclusive_minimum,
+                new.exclusive_minimum,
+                false,
+                state,
+            );
+            bound("maximum", old.maximum, new.maximum, true, state);
+            bound(
+                "exclusiveMaximum",
+                old.exclusive_maximum,
+                new.exclusive_maximum,
+                true,
+                state,
+            );
+            enumeration(old.enumeration.as_ref(), new.enumeration.as_ref(), state);
+        }
+        (FieldEnum::String(old), FieldEnum::String(new)) => {
+            bound("minLength", old.min_length, new.min_length, false, state);
+            bound("maxLength", old.max_length, new.max_length, true, state);
+            keyword(
+                "pattern",
+                old.pattern.as_ref().map(|p| p.pattern.as_str()),
+                new.pattern.as_ref().map(|p| p.pattern.as_str()),
+                state,
+            );
+            // the transforms change the values before they are checked, so neither direction
+            if old.transforms != new.transforms {
+                state.report(Change::Constraint {
+                    keyword: "transforms",
+                    old: old.transforms.as_ref().and_then(value),
+                    new: new.transforms.as_ref().and_then(value),
+                    direction: Direction::Changed,
+                });
+            }
+            enumeration(old.enumeration.as_ref(), new.enumeration.as_ref(), state);
+        }
+        (FieldEnum::Array(old), FieldEnum::Array(new)) => {
+            let unique = |unique: Option<bool>| unique.filter(|u| *u);
+            keyword("unique", unique(old.unique), unique(new.unique), state);
+            match (&old.item, &new.item) {
+                (None, None) => {}
+                (None, Some(item)) => state.report_child(item.name(), Change::ItemAdded),
+                (Some(item), None) => state.report_child(item.name(), Change::ItemRemoved),
+                (Some(old), Some(new)) => {
+                    state.field_names.push(new.name());
+                    diff_field(old, new, state);
+                    state.field_names.pop();
+                }
+            }
+        }
+        (FieldEnum::Object(old), FieldEnum::Object(new)) => {
+            let old_required = old.required.as_deref().unwrap_or_default();
+            let new_required = new.required.as_deref().unwrap_or_default();
+            for key in old_required.iter().filter(|k| !new_required.contains(k)) {
+                state.report(Change::RequiredRemoved(key.clone()));
+            }
+            for key in new_required.iter().filter(|k| !old_required.contains(k)) {
+                state.report(Change::RequiredAdded(key.clone()));
+            }
+
+            for (key, property) in &old.properties {
+                if !new.properties.contains_key(key) {
+                    state.report_child(
+                        property.name(),
+                        Change::PropertyRemoved {
+                            additional_properties: new.additional_properties.is_some(),
+                        },
+                    );
+                }
+            }
+            for (key, property) in &new.properties {
+                match old.properties.get(key) {
+                    None => state.report_child(
+                        property.name(),
+                        Change::PropertyAdded {
+                            additional_properties: old.additional_properties.is_some(),
+                        },
+                    ),
+                    Some(old) => {
+                        state.field_names.push(property.name());
+                        diff_field(old, property, state);
+                        state.field_names.pop();
+                    }
+                }
+            }
+
+            match (&old.additional_properties, &new.additional_properties) {
+                (None, None) => {}
+                (None, Some(additional)) => {
+                    state.report_child(additional.name(), Change::AdditionalPropertiesAdded)
+                }
+                (Some(additional), None) => {
+                    state.report_child(additional.name(), Change::AdditionalPropertiesRemoved)
+                }
+                (Some(old), Some(new)) => {
+                    state.field_names.push(new.name());
+                    diff_field(old, new, state);
+                    state.field_names.pop();
+                }
+            }
+        }
+        (FieldEnum::Reference(old), FieldEnum::Reference(new))
+            if old.reference != new.reference =>
+        {
+            state.report(Change::ReferenceChanged {
+                old: old.reference.clone(),
+                new: new.reference.clone(),
+            });
+        }
+        (old, new) if old.field_type() != new.field_type() => {
+            state.report(Change::TypeChanged {
+                old: old.field_type(),
+                new: new.field_type(),
+            });
+        }
+        // the other fields have no constraints
+        _ => {}
+    }
+}
+
+/// Compares two versions of a schema, the metadata (such as `title`) is not compared.
+pub fn diff(old: &FieldEnum, new: &FieldEnum) -> Diff {
+    let mut state = State {
+        field_names: vec![new.name()],
+        differences: vec![],
+    };
+    diff_field(old, new, &mut state);
+    Diff {
+        differences: state.differences,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::{FieldEnum, FieldType};
+    use crate::visitor::diff::{diff, Change, Difference, Direction};
+    use serde_json::json;
+
+    fn field(value: serde_json::Value) -> FieldEnum {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn old() -> FieldEnum {
+        field(json!({
+            "type": "object",
+            "name": "client",
+            "properties": {
+                "name": {"type": "string", "name": "name", "maxLength": 64},
+                "age": {"type": "uinteger", "name": "age", "minimum": 0, "maximum": 200},
+                "level": {"type": "string", "name": "level", "enum": ["basic", "gold"]},
+                "nickname": {"type": "string", "name": "nickname"},
+                "score": {"type": "integer", "name": "score"},
+                "tags": {
+                    "type": "array",
+                    "name": "tags",
+                    "item": {"type": "string", "name": "tag", "pattern": "^[a-z]+$"}
+                }
+            },
+            "required": ["name"]
+        }))
+    }
+
+    fn new() -> FieldEnum {
+        field(json!({
+            "type": "object",
+            "name": "client",
+            "title": "Client",
+            "properties": {
+                "name": {"type": "string", "name": "name", "maxLength": 32, "minLength": 1},
+                "age": {"type": "uinteger", "name": "age", "maximum": 250},
+                "level": {"type": "string", "name": "level", "enum": ["basic", "premium"]},
+                "email": {"type": "email", "name": "email"},
+                "score": {"type": "float", "name": "score"},
+                "tags": {
+                    "type": "array",
+                    "name": "tags",
+                    "item": {"type": "string", "name": "tag", "pattern": "^[a-z0-9]+$"},
+                    "unique": true
+                }
+            },
+            "required": ["name", "email"]
+        }))
+    }
+
+    #[test]
+    fn test_diff() {
+        let diff = diff(&old(), &new());
+        let difference = |path: &str, change: Change| Difference {
+            path: path.to_string(),
+            change,
+        };
+        assert_eq!(
+            diff.differences,
+            vec![
+                difference("client", Change::RequiredAdded("email".to_string())),
+                difference(
+                    "client/nickname",
+                    Change::PropertyRemoved {
+                        additional_properties: false
+                    }
+                ),
+                difference(
+                    "client/age",
+                    Change::Constraint {
+                        keyword: "minimum",
+                        old: Some(json!(0)),
+                        new: None,
+                        direction: Direction::Loosened
+                    }
+                ),
+                difference(
+                    "client/age",
+                    Change::Constraint {
+                        keyword: "maximum",
+                        old: Some(json!(200)),
+                        new: Some(json!(250)),
+                        direction: Direction::Loosened
+                    }
+                ),
+                difference(
+                    "client/email",
+                    Change::PropertyAdded {
+                        additional_properties: false
+                    }
+                ),
+                difference("client/level", Change::EnumValueRemoved(json!("gold"))),
+                difference("client/level", Change::EnumValueAdded(json!("premium"))),
+                difference(
+                    "client/name",
+                    Change::Constraint {
+                        keyword: "minLength",
+                        old: None,
+                        new: Some(json!(1)),
+                        direction: Direction::Tightened
+                    }
+                ),
+                difference(
+                    "client/name",
+                    Change::Constraint {
+                        keyword: "maxLength",
+                        old: Some(json!(64)),
+                        new: Some(json!(32)),
+                        direction: Direction::Tightened
+                    }
+                ),
+                difference(
+                    "client/score",
+                    Change::TypeChanged {
+                        old: FieldType::Integer,
+                        new: FieldType::Float
+                    }
+                ),
+                difference(
+                    "client/tags",
+                    Change::Constraint {
+                        keyword: "unique",
+                        old: None,
+                        new: Some(json!(true)),
+                        direction: Direction::Tightened
+                    }
+                ),
+                difference(
+                    "client/tags/tag",
+                    Change::Constraint {
+                        keyword: "pattern",
+                        old: Some(json!("^[a-z]+$")),
+                        new: Some(json!("^[a-z0-9]+$")),
+                        direction: Direction::Changed
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let report = diff(&old(), &new()).to_string();
+        assert!(report.starts_with("client: required property email added\n"));
+        assert!(report.contains("client/name: maxLength tightened from 64 to 32\n"));
+        assert!(report.contains("client/age: minimum loosened from 0 to none\n"));
+        assert!(report.contains("client/level: enum value \"gold\" removed\n"));
+        assert!(report.contains("client/score: type changed from integer to float\n"));
+        assert!(diff(&new(), &new()).is_empty());
+    }
+}
@@ -0,0 +1,784 @@
+use crate::core::field::array::ArrayField;
+use crate::core::field::boolean::BooleanField;
+use crate::core::field::date::DateField;
+use crate::core::field::datetime::DatetimeField;
+use crate::core::field::email::EmailField;
+use crate::core::field::float::FloatField;
+use crate::core::field::integer::IntegerField;
+use crate::core::field::metadata::Metadata;
+use crate::core::field::object::ObjectField;
+use crate::core::field::reference::ReferenceField;
+use crate::core::field::string::StringField;
+use crate::core::field::time::TimeField;
+use crate::core::field::uinteger::UIntegerField;
+use crate::core::field::FieldEnum;
+use crate::core::value::FieldValue;
+use crate::visitor::validator::ValidationError;
+use crate::visitor::walk::{walk, Path, SchemaVisitor};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// Stands for the index of a new item in the `<template>` of an array.
+pub const INDEX_PLACEHOLDER: &str = "__index__";
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn label(metadata: &Metadata, path: &Path) -> String {
+    match (&metadata.title, path.parent().map(|p| p.field())) {
+        (Some(title), _) => escape(title),
+        (None, Some(FieldEnum::Array(_))) => escape(&path.field().name()),
+        (None, _) => escape(path.segment()),
+    }
+}
+
+/// Whether the pattern means the same to the Rust regex and to the HTML `pattern` attribute.
+fn portable(pattern: &str) -> bool {
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(e) if "dDwWsS.*+?()[]{}|^$\\".contains(e) => {}
+                _ => return false,
+            },
+            '(' if chars.peek() == Some(&'?') => {
+                chars.next();
+                if chars.next() != Some(':') {
+                    return false;
+                }
+            }
+            '{' => {
+                let mut bounds = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) if c.is_ascii_digit() || c == ',' => bounds.push(c),
+                        _ => return false,
+                    }
+                }
+                let mut bounds = bounds.split(',');
+                if matches!(bounds.next(), None | Some("")) || bounds.count() > 1 {
+                    return false;
+                }
+            }
+            '[' => {
+                if chars.peek() == Some(&'^') {
+                    chars.next();
+                }
+                let mut empty = true;
+                let mut start = None;
+                loop {
+                    match chars.next() {
+                        Some(']') if !empty => break,
+                        Some('\\') => match chars.next() {
+                            Some(e) if "dDwWsS.-[]^\\".contains(e) => start = None,
+                            _ => return false,
+                        },
+                        Some('-') => match (start.take(), chars.next()) {
+                            (Some(start), Some(end)) if end.is_alphanumeric() && start <= end => {}
+                            _ => return false,
+                        },
+                        Some(c) if c.is_alphanumeric() || c == '_' || c == ' ' => start = Some(c),
+                        _ => return false,
+                    }
+                    empty = false;
+                }
+            }
+            ']' | '}' => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+fn attributes(attributes: &[(&str, Option<String>)]) -> String {
+    attributes
+        .iter()
+        .filter_map(|(name, value)| {
+            value.as_ref().map(|value| match value.is_empty() {
+                true => format!(" {}", name),
+                false => format!(" {}=\"{}\"", name, escape(value)),
+            })
+        })
+        .collect()
+}
+
+fn flag(on: bool) -> Option<String> {
+    on.then(String::new)
+}
+
+fn some(value: impl Display) -> Option<String> {
+    Some(value.to_string())
+}
+
+struct Form<'a> {
+    value: Option<&'a FieldValue>,
+    errors: Option<&'a BTreeMap<String, Vec<ValidationError>>>,
+    html: String,
+    depth: usize,
+}
+
+impl<'a> Form<'a> {
+    fn line(&mut self, line: &str) {
+        self.html.push_str(&"  ".repeat(self.depth));
+        self.html.push_str(line);
+        self.html.push('\n');
+    }
+
+    fn value_at(&self, path: &Path) -> Option<&'a FieldValue> {
+        let value = match path.parent() {
+            None => self.value?,
+            Some(parent) => match (self.value_at(parent)?, path.key()?) {
+                (FieldValue::Object(o), key) => o.get(key)?,
+                (FieldValue::Array(values), index) => values.get(index.parse::<usize>().ok()?)?,
+                _ => return None,
+            },
+        };
+        Some(value).filter(|v| !matches!(v, FieldValue::Null))
+    }
+
+    fn errors_at(&self, path: &Path) -> &'a [ValidationError] {
+        self.errors
+            .and_then(|errors| errors.get(&path.to_string()))
+            .map(|errors| errors.as_slice())
+            .unwrap_or_default()
+    }
+
+    fn render_errors(&mut self, path: &Path) {
+        let errors = self.errors_at(path);
+        if errors.is_empty() {
+            return;
+        }
+        self.line("<ul class=\"errors\">");
+        for error in errors {
+            self.line(&format!("  <li>{}</li>", escape(&error.to_string())));
+        }
+        self.line("</ul>");
+    }
+
+    fn open_field(&mut self, metadata: &Metadata, path: &Path) {
+        let class = match self.errors_at(path).is_empty() {
+            true => "field",
+            false => "field invalid",
+        };
+        self.line(&format!("<div class=\"{}\">", class));
+        self.depth += 1;
+        self.line(&format!(
+            "<label for=\"{}\">{}</label>",
+            escape(&path.to_string()),
+            label(metadata, path)
+        ));
+        if let Some(description) = &metadata.description {
+            self.line(&format!(
+                "<small class=\"description\">{}</small>",
+                escape(description)
+            ));
+        }
+    }
+
+    fn close_field(&mut self, path: &Path) {
+        self.render_errors(path);
+        self.depth -= 1;
+        self.line("</div>");
+    }
+
+    fn common(&self, metadata: &Metadata, path: &Path, required: bool) -> String {
+        let path_name = path.to_string();
+        attributes(&[
+            ("id", Some(path_name.clone())),
+            ("name", Some(path_name)),
+            ("required", flag(required && is_required(path))),
+            ("readonly", flag(metadata.read_only == Some(true))),
+            (
+                "aria-invalid",
+                (!self.errors_at(path).is_empty()).then(|| "true".to_string()),
+            ),
+        ])
+    }
+
+    fn input(
+        &mut self,
+        metadata: &Metadata,
+        path: &Path,
+        input_type: &str,
+        extra: &[(&str, Option<String>)],
+        value: Option<String>,
+    ) {
+        self.open_field(metadata, path);
+        let html = format!(
+            "<input type=\"{}\"{}{}{}>",
+            input_type,
+            self.common(metadata, path, true),
+            attributes(extra),
+            value
+                .map(|v| format!(" value=\"{}\"", escape(&v)))
+                .unwrap_or_default()
+        );
+        self.line(&html);
+        self.close_field(path);
+    }
+
+    fn select<T: Display>(&mut self, metadata: &Metadata, path: &Path, options: &[T]) {
+        self.open_field(metadata, path);
+        let selected = self.value_at(path).map(|v| v.to_string());
+        let html = format!("<select{}>", self.common(metadata, path, true));
+        self.line(&html);
+        self.depth += 1;
+        if !is_required(path) {
+            self.line("<option value=\"\"></option>");
+        }
+        for option in options {
+            let option = option.to_string();
+            self.line(&format!(
+                "<option value=\"{0}\"{1}>{0}</option>",
+                escape(&option),
+                attributes(&[("selected", flag(selected.as_ref() == Some(&option)))])
+            ));
+        }
+        self.depth -= 1;
+        self.line("</select>");
+        self.close_field(path);
+    }
+
+    fn text(&self, path: &Path) -> Option<String> {
+        self.value_at(path).map(|v| v.to_string())
+    }
+
+    fn render_property(&mut self, property: &FieldEnum, segment: String, key: String, path: &Path) {
+        self.visit_field(property, &path.child(segment, Some(key), property));
+    }
+}
+
+fn is_required(path: &Path) -> bool {
+    match path.parent().map(|p| p.field()) {
+        Some(FieldEnum::Object(o)) => o
+            .required
+            .as_ref()
+            .is_some_and(|required| required.iter().any(|r| Some(r.as_str()) == path.key())),
+        _ => false,
+    }
+}
+
+fn integer_bounds<T: Ord + Copy>(
+    minimum: Option<T>,
+    exclusive_minimum: Option<T>,
+    maximum: Option<T>,
+    exclusive_maximum: Option<T>,
+    next: impl Fn(T) -> T,
+    previous: impl Fn(T) -> T,
+) -> (Option<T>, Option<T>) {
+    let lower = minimum.into_iter().chain(exclusive_minimum.map(next)).max();
+    let upper = maximum
+        .into_iter()
+        .chain(exclusive_maximum.map(previous))
+        .min();
+    (lower, upper)
+}
+
+impl SchemaVisitor for Form<'_> {
+    fn visit_array(&mut self, field: &ArrayField, path: &Path) {
+        self.line(&format!(
+            "<fieldset class=\"array\" id=\"{}\" data-repeatable>",
+            escape(&path.to_string())
+        ));
+        self.depth += 1;
+        self.line(&format!(
+            "<legend>{}</legend>",
+            label(&field.metadata, path)
+        ));
+        if let Some(description) = &field.metadata.description {
+            self.line(&format!(
+                "<small class=\"description\">{}</small>",
+                escape(description)
+            ));
+        }
+        if let Some(item) = &field.item {
+            let items = match self.value_at(path) {
+                Some(FieldValue::Array(values)) => values.len(),
+                _ => 0,
+            };
+            for index in 0..items {
+                self.line("<div class=\"item\">");
+                self.depth += 1;
+                self.render_property(item, index.to_string(), index.to_string(), path);
+                self.depth -= 1;
+                self.line("</div>");
+            }
+            self.line("<template>");
+            self.depth += 1;
+            self.line("<div class=\"item\">");
+            self.depth += 1;
+            self.render_property(
+                item,
+                INDEX_PLACEHOLDER.to_string(),
+                INDEX_PLACEHOLDER.to_string(),
+                path,
+            );
+            self.depth -= 1;
+            self.line("</div>");
+            self.depth -= 1;
+            self.line("</template>");
+        }
+        self.render_errors(path);
+        self.depth -= 1;
+        self.line("</fieldset>");
+    }
+
+    fn visit_object(&mut self, field: &ObjectField, path: &Path) {
+        let root = path.parent().is_none();
+        if !root {
+            self.line(&format!(
+                "<fieldset class=\"object\" id=\"{}\">",
+                escape(&path.to_string())
+            ));
+            self.depth += 1;
+            self.line(&format!(
+                "<legend>{}</legend>",
+                label(&field.metadata, path)
+            ));
+            if let Some(description) = &field.metadata.description {
+                self.line(&format!(
+                    "<small class=\"description\">{}</small>",
+                    escape(description)
+                ));
+            }
+        }
+        for (key, property) in &field.properties {
+            self.render_property(property, property.name(), key.clone(), path);
+        }
+        if let (Some(additional), Some(FieldValue::Object(values))) =
+            (&field.additional_properties, self.value_at(path))
+        {
+            for key in values.keys() {
+                if !field.properties.contains_key(key) {
+                    self.render_property(additional, key.clone(), key.clone(), path);
+                }
+            }
+        }
+        self.render_errors(path);
+        if !root {
+            self.depth -= 1;
+            self.line("</fieldset>");
+        }
+    }
+
+    fn visit_boolean(&mut self, field: &BooleanField, path: &Path) {
+        let checked = matches!(self.value_at(path), Some(FieldValue::Boolean(true)));
+        self.open_field(&field.metadata, path);
+        // a required checkbox could never be submitted unchecked
+        let html = format!(
+            "<input type=\"checkbox\"{} value=\"true\"{}>",
+            self.common(&field.metadata, path, false),
+            attributes(&[("checked", flag(checked))])
+        );
+        self.line(&html);
+        self.close_field(path);
+    }
+
+    fn visit_float(&mut self, field: &FloatField, path: &Path) {
+        if let Some(enumeration) = &field.enumeration {
+            return self.select(&field.metadata, path, enumeration);
+        }
+        let value = self.text(path);
+        self.input(
+            &field.metadata,
+            path,
+            "number",
+            &[
+                ("min", field.minimum.map(|v| v.to_string())),
+                ("max", field.maximum.map(|v| v.to_string())),
+                (
+                    "data-exclusive-minimum",
+                    field.exclusive_minimum.map(|v| v.to_string()),
+                ),
+                (
+                    "data-exclusive-maximum",
+                    field.exclusive_maximum.map(|v| v.to_string()),
+                ),
+                ("step", some("any")),
+            ],
+            value,
+        );
+    }
+
+    fn visit_integer(&mut self, field: &IntegerField, path: &Path) {
+        if let Some(enumeration) = &field.enumeration {
+            return self.select(&field.metadata, path, enumeration);
+        }
+        let (min, max) = integer_bounds(
+            field.minimum,
+            field.exclusive_minimum,
+            field.maximum,
+            field.exclusive_maximum,
+            |v| v.saturating_add(1),
+            |v| v.saturating_sub(1),
+        );
+        let value = self.text(path);
+        self.input(
+            &field.metadata,
+            path,
+            "number",
+            &[
+                ("min", min.map(|v| v.to_string())),
+                ("max", max.map(|v| v.to_string())),
+                ("step", some(1)),
+            ],
+            value,
+        );
+    }
+
+    fn visit_uinteger(&mut self, field: &UIntegerField, path: &Path) {
+        if let Some(enumeration) = &field.enumeration {
+            return self.select(&field.metadata, path, enumeration);
+        }
+        let (min, max) = integer_bounds(
+            field.minimum,
+            field.exclusive_minimum,
+            field.maximum,
+            field.exclusive_maximum,
+            |v| v.saturating_add(1),
+            |v| v.saturating_sub(1),
+        );
+        let value = self.text(path);
+        self.input(
+            &field.metadata,
+            path,
+            "number",
+            &[
+                ("min", some(min.unwrap_or(0))),
+                ("max", max.map(|v| v.to_string())),
+                ("step", some(1)),
+            ],
+            value,
+        );
+    }
+
+    fn visit_string(&mut self, field: &StringField, path: &Path) {
+        if let Some(enumeration) = &field.enumeration {
+            return self.select(&field.metadata, path, enumeration);
+        }
+        let value = self.text(path);
+        self.input(
+            &field.metadata,
+            path,
+            "text",
+            &[
+                ("minlength", field.min_length.map(|v| v.to_string())),
+                ("maxlength", field.max_length.map(|v| v.to_string())),
+                // the pattern of an input has to match the whole value
+                (
+                    "pattern",
+                    field
+                        .pattern
+                        .as_ref()
+                        .map(|p| p.pattern.as_str())
+                        .filter(|p| portable(p))
+                        .map(|p| format!(".*(?:{}).*", p)),
+                ),
+            ],
+            value,
+        );
+    }
+
+    fn visit_email(&mut self, field: &EmailField, path: &Path) {
+        let value = self.text(path);
+        self.input(&field.metadata, path, "email", &[], value);
+    }
+
+    fn visit_datetime(&mut self, field: &DatetimeField, path: &Path) {
+        let value = match self.value_at(path) {
+            Some(FieldValue::DateTime(v)) => Some(v.format("%Y-%m-%dT%H:%M:%S").to_string()),
+            value => value.map(|v| v.to_string()),
+        };
+        self.input(
+            &field.metadata,
+            path,
+            "datetime-local",
+            &[("step", some(1))],
+            value,
+        );
+    }
+
+    fn visit_date(&mut self, field: &DateField, path: &Path) {
+        let value = self.text(path);
+        self.input(&field.metadata, path, "date", &[], value);
+    }
+
+    fn visit_time(&mut self, field: &TimeField, path: &Path) {
+        let value = self.text(path);
+        self.input(&field.metadata, path, "time", &[("step", some(1))], value);
+    }
+
+    fn visit_reference(&mut self, field: &ReferenceField, path: &Path) {
+        // only the referred fields with values are rendered, since a recursive schema has no end
+        let Some(parent) = path.parent() else {
+            return;
+        };
+        if let (Some(resolved), Some(_), Some(key)) = (
+            parent.find(&field.reference),
+            self.value_at(path),
+            path.key(),
+        ) {
+            self.render_property(
+                resolved,
+                path.segment().to_string(),
+                key.to_string(),
+                parent,
+            );
+        }
+    }
+}
+
+/// Renders the schema into an HTML form, with the value filled in and the errors of the
+/// validator (with indexed paths) next to their fields. An input is named by its path, e.g.
+/// `client/tags/0`, and a reference is only rendered where the value has data for it.
+pub fn render(
+    schema: &FieldEnum,
+    value: Option<&FieldValue>,
+    errors: Option<&BTreeMap<String, Vec<ValidationError>>>,
+) -> String {
+    let mut form = Form {
+        value,
+        errors,
+        html: String::new(),
+        depth: 1,
+    };
+    walk(&mut form, schema);
+    format!(
+        "<form id=\"{}\">\n{}</form>\n",
+        escape(&schema.name()),
+        form.html
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::visitor::form::render;
+    use crate::visitor::json::from_json;
+    use crate::visitor::validator::{Validator, ValidatorOptions};
+    use serde_json::json;
+
+    fn client_schema() -> FieldEnum {
+        serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "client",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "name": "name",
+                        "title": "Full name",
+                        "description": "As in the passport",
+                        "maxLength": 32,
+                        "pattern": "^[A-Z]"
+                    },
+                    "age": {
+                        "type": "uinteger",
+                        "name": "age",
+                        "exclusiveMaximum": 200
+                    },
+                    "score": {
+                        "type": "float",
+                        "name": "score",
+                        "minimum": 0.5,
+                        "exclusiveMaximum": 1.0
+                    },
+                    "level": {
+                        "type": "string",
+                        "name": "level",
+                        "enum": ["basic", "premium"]
+                    },
+                    "active": {"type": "boolean", "name": "active"},
+                    "email": {"type": "email", "name": "email"},
+                    "birthday": {"type": "date", "name": "birthday"},
+                    "wakeup": {"type": "time", "name": "wakeup"},
+                    "updated": {"type": "datetime", "name": "updated"},
+                    "tags": {
+                        "type": "array",
+                        "name": "tags",
+                        "item": {"type": "string", "name": "tag", "minLength": 1}
+                    },
+                    "address": {
+                        "type": "object",
+                        "name": "address",
+                        "properties": {
+                            "city": {"type": "string", "name": "city"}
+                        }
+                    }
+                },
+                "required": ["name", "level", "active"]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_render() {
+        let html = render(&client_schema(), None, None);
+        assert!(html.starts_with("<form id=\"client\">\n"));
+        assert!(html.ends_with("</form>\n"));
+        for expected in [
+            "<label for=\"client/name\">Full name</label>",
+            "<small class=\"description\">As in the passport</small>",
+            "<input type=\"text\" id=\"client/name\" name=\"client/name\" required maxlength=\"32\" pattern=\".*(?:^[A-Z]).*\">",
+            "<input type=\"number\" id=\"client/age\" name=\"client/age\" min=\"0\" max=\"199\" step=\"1\">",
+            "<input type=\"number\" id=\"client/score\" name=\"client/score\" min=\"0.5\" data-exclusive-maximum=\"1\" step=\"any\">",
+            "<select id=\"client/level\" name=\"client/level\" required>",
+            "<option value=\"premium\">premium</option>",
+            "<input type=\"checkbox\" id=\"client/active\" name=\"client/active\" value=\"true\">",
+            "<input type=\"email\" id=\"client/email\" name=\"client/email\">",
+            "<input type=\"date\" id=\"client/birthday\" name=\"client/birthday\">",
+            "<input type=\"time\" id=\"client/wakeup\" name=\"client/wakeup\" step=\"1\">",
+            "<input type=\"datetime-local\" id=\"client/updated\" name=\"client/updated\" step=\"1\">",
+            "<fieldset class=\"array\" id=\"client/tags\" data-repeatable>",
+            "<input type=\"text\" id=\"client/tags/__index__\" name=\"client/tags/__index__\" minlength=\"1\">",
+            "<fieldset class=\"object\" id=\"client/address\">",
+            "<input type=\"text\" id=\"client/address/city\" name=\"client/address/city\">",
+        ] {
+            assert!(html.contains(expected), "{} not in {}", expected, html);
+        }
+        assert!(!html.contains("<option value=\"\">"));
+    }
+
+    #[test]
+    fn test_render_array() {
+        let schema: FieldEnum = serde_json::from_value(json!({
+            "type": "array",
+            "name": "tags",
+            "item": {"type": "string", "name": "tag"}
+        }))
+        .unwrap();
+        let value = from_json(&schema, &json!(["a"]));
+        assert_eq!(
+            render(&schema, Some(&value), None),
+            r#"<form id="tags">
+  <fieldset class="array" id="tags" data-repeatable>
+    <legend>tags</legend>
+    <div class="item">
+      <div class="field">
+        <label for="tags/0">tag</label>
+        <input type="text" id="tags/0" name="tags/0" value="a">
+      </div>
+    </div>
+    <template>
+      <div class="item">
+        <div class="field">
+          <label for="tags/__index__">tag</label>
+          <input type="text" id="tags/__index__" name="tags/__index__">
+        </div>
+      </div>
+    </template>
+  </fieldset>
+</form>
+"#
+        );
+    }
+
+    #[test]
+    fn test_render_value_and_errors() {
+        let schema = client_schema();
+        let value = from_json(
+            &schema,
+            &json!({
+                "name": "robert <li>",
+                "level": "premium",
+                "active": true,
+                "updated": "2023-09-19T07:30:00Z",
+                "tags": ["vip", ""]
+            }),
+        );
+        let errors = Validator::with_options(schema, ValidatorOptions::new().indexed_paths(true))
+            .validate(&value)
+            .err()
+            .unwrap();
+        let html = render(&client_schema(), Some(&value), Some(&errors));
+        for expected in [
+            "<div class=\"field invalid\">",
+            "name=\"client/name\" required aria-invalid=\"true\" maxlength=\"32\" pattern=\".*(?:^[A-Z]).*\" value=\"robert &lt;li&gt;\">",
+            "<li>^[A-Z] does not match pattern robert &lt;li&gt; (Pattern)</li>",
+            "<option value=\"premium\" selected>premium</option>",
+            "name=\"client/active\" value=\"true\" checked>",
+            "name=\"client/updated\" step=\"1\" value=\"2023-09-19T07:30:00\">",
+            "name=\"client/tags/0\" minlength=\"1\" value=\"vip\">",
+            "name=\"client/tags/1\" aria-invalid=\"true\" minlength=\"1\" value=\"\">",
+        ] {
+            assert!(html.contains(expected), "{} not in {}", expected, html);
+        }
+    }
+
+    #[test]
+    fn test_render_reference() {
+        let schema: FieldEnum = serde_json::from_value(json!({
+            "type": "object",
+            "name": "category",
+            "properties": {
+                "name": {"type": "string", "name": "name"},
+                "parent": {"type": "reference", "name": "parent", "reference": "category"}
+            }
+        }))
+        .unwrap();
+        assert!(!render(&schema, None, None).contains("category/parent"));
+
+        let value = from_json(&schema, &json!({"parent": {"name": "books"}}));
+        let html = render(&schema, Some(&value), None);
+        assert!(html.contains("<fieldset class=\"object\" id=\"category/parent\">"));
+        assert!(html.contains("name=\"category/parent/name\" value=\"books\">"));
+        assert!(!html.contains("category/parent/parent"));
+    }
+
+    #[test]
+    fn test_property_key() {
+        let schema: FieldEnum = serde_json::from_value(json!({
+            "type": "object",
+            "name": "client",
+            "properties": {
+                "full_name": {"type": "string", "name": "name"}
+            },
+            "required": ["full_name"]
+        }))
+        .unwrap();
+        let value = from_json(&schema, &json!({"full_name": "Robert", "name": "Bob"}));
+        let html = render(&schema, Some(&value), None);
+        assert!(
+            html.contains("name=\"client/name\" required value=\"Robert\">"),
+            "{}",
+            html
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        for (pattern, portable) in [
+            ("^[A-Z][a-z_]*$", true),
+            ("(?:ab|cd){2,3}\\d+", true),
+            ("[^a-z0-9 ]\\.", true),
+            ("(?i)robert", false),
+            ("\\p{L}+", false),
+            ("[a-z-]", false),
+            ("[[:alpha:]]", false),
+            ("\\Aabc\\z", false),
+            ("a\\-b", false),
+        ] {
+            let schema: FieldEnum = serde_json::from_value(
+                json!({"type": "string", "name": "name", "pattern": pattern}),
+            )
+            .unwrap();
+            let html = render(&schema, None, None);
+            assert_eq!(html.contains(" pattern="), portable, "{}", pattern);
+        }
+    }
+}
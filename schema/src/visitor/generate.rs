@@ -0,0 +1,593 @@
+use crate::core::constraint::Constraint;
+use crate::core::field::array::ArrayField;
+use crate::core::field::boolean::BooleanField;
+use crate::core::field::date::DateField;
+use crate::core::field::datetime::DatetimeField;
+use crate::core::field::email::EmailField;
+use crate::core::field::float::FloatField;
+use crate::core::field::integer::IntegerField;
+use crate::core::field::object::ObjectField;
+use crate::core::field::reference::ReferenceField;
+use crate::core::field::string::StringField;
+use crate::core::field::time::TimeField;
+use crate::core::field::uinteger::UIntegerField;
+use crate::core::field::{Field, FieldEnum};
+use crate::core::transform::apply;
+use crate::core::value::FieldValue;
+use crate::visitor::walk::{walk_array_value, walk_object_value, Path, ValueVisitor};
+use chrono::{DateTime, NaiveDate, NaiveTime};
+use email_address_parser::EmailAddress;
+use rand::distributions::{Alphanumeric, DistString};
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+use rand::{Rng, SeedableRng};
+use regex_syntax::hir::{Hir, HirKind};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+pub const DEFAULT_GENERATED_ITEMS: usize = 4;
+
+pub const DEFAULT_GENERATED_DEPTH: usize = 4;
+
+const SPAN: i64 = 1000;
+
+const ATTEMPTS: usize = 64;
+
+const BATCHES: usize = 4;
+
+/// No value of the field satisfies its constraints.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("can not generate a value of {path}: {message}")]
+pub struct GenerateError {
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct GeneratorOptions {
+    max_items: usize,
+    max_depth: usize,
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        GeneratorOptions {
+            max_items: DEFAULT_GENERATED_ITEMS,
+            max_depth: DEFAULT_GENERATED_DEPTH,
+        }
+    }
+}
+
+impl GeneratorOptions {
+    pub fn new() -> Self {
+        GeneratorOptions::default()
+    }
+
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.max_items = max;
+        self
+    }
+
+    /// Beyond the depth only the required properties are generated, and the arrays are empty.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    pub(crate) fn is_deep(&self, depth: usize) -> bool {
+        depth > self.max_depth
+    }
+}
+
+fn word(rng: &mut impl Rng) -> String {
+    let len = rng.gen_range(1..=12);
+    Alphanumeric.sample_string(rng, len).to_lowercase()
+}
+
+fn is_valid(constraints: &[Box<dyn Constraint>], value: &FieldValue) -> bool {
+    constraints.iter().all(|c| c.is_valid(&value.into()))
+}
+
+/// Removes the anchors and the word boundaries, which can not be generated from.
+fn unanchor(hir: Hir) -> Hir {
+    match hir.into_kind() {
+        HirKind::Empty | HirKind::Anchor(_) | HirKind::WordBoundary(_) => Hir::empty(),
+        HirKind::Literal(literal) => Hir::literal(literal),
+        HirKind::Class(class) => Hir::class(class),
+        HirKind::Repetition(mut repetition) => {
+            repetition.hir = Box::new(unanchor(*repetition.hir));
+            Hir::repetition(repetition)
+        }
+        HirKind::Group(mut group) => {
+            group.hir = Box::new(unanchor(*group.hir));
+            Hir::group(group)
+        }
+        HirKind::Concat(hirs) => Hir::concat(hirs.into_iter().map(unanchor).collect()),
+        HirKind::Alternation(hirs) => Hir::alternation(hirs.into_iter().map(unanchor).collect()),
+    }
+}
+
+/// Generates random values which satisfy the schema, for tests and demo environments.
+#[derive(Debug)]
+pub struct Generator {
+    schema: FieldEnum,
+    options: GeneratorOptions,
+}
+
+impl Generator {
+    pub fn new(field: impl Into<FieldEnum>) -> Self {
+        Generator::with_options(field, GeneratorOptions::default())
+    }
+
+    pub fn with_options(field: impl Into<FieldEnum>, options: GeneratorOptions) -> Self {
+        Generator {
+            schema: field.into(),
+            options,
+        }
+    }
+
+    pub fn schema(&self) -> &FieldEnum {
+        &self.schema
+    }
+
+    /// The same seed always generates the same value.
+    pub fn generate(&self, seed: u64) -> Result<FieldValue, GenerateError> {
+        self.generate_with(&mut StdRng::seed_from_u64(seed))
+    }
+
+    pub fn generate_with<R: Rng>(&self, rng: &mut R) -> Result<FieldValue, GenerateError> {
+        generate_at(&Path::root(&self.schema), &self.options, rng)
+    }
+
+    /// A proptest strategy of the valid values, the seeds which fail to generate are rejected.
+    #[cfg(feature = "proptest")]
+    pub fn into_strategy(self) -> impl proptest::strategy::Strategy<Value = FieldValue> {
+        use proptest::strategy::Strategy;
+        let generator = std::sync::Arc::new(self);
+        proptest::num::u64::ANY
+            .prop_filter_map("no valid value", move |seed| generator.generate(seed).ok())
+    }
+}
+
+pub(crate) fn generate_at<R: Rng>(
+    path: &Path,
+    options: &GeneratorOptions,
+    rng: &mut R,
+) -> Result<FieldValue, GenerateError> {
+    let mut filler = Filler {
+        rng,
+        options,
+        error: None,
+    };
+    let mut value = FieldValue::Null;
+    filler.visit_value(path.field(), &mut value, path);
+    match filler.error {
+        Some(error) => Err(error),
+        None => Ok(value),
+    }
+}
+
+struct Filler<'a, R> {
+    rng: R,
+    options: &'a GeneratorOptions,
+    error: Option<GenerateError>,
+}
+
+impl<R: Rng> Filler<'_, R> {
+    fn fail(&mut self, path: &Path, message: impl Into<String>) {
+        if self.error.is_none() {
+            self.error = Some(GenerateError {
+                path: path.to_string(),
+                message: message.into(),
+            });
+        }
+    }
+
+    fn fill(&mut self, value: &mut FieldValue, generated: Option<FieldValue>, path: &Path) {
+        match generated {
+            Some(generated) => *value = generated,
+            None => self.fail(path, "no value satisfies the constraints"),
+        }
+    }
+
+    fn is_deep(&self, path: &Path) -> bool {
+        self.options.is_deep(path.depth()) || path.depth() >= self.max_depth()
+    }
+
+    fn len(&mut self, path: &Path) -> usize {
+        match self.is_deep(path) {
+            true => 0,
+            false => self.rng.gen_range(0..=self.options.max_items),
+        }
+    }
+
+    fn choose<T: Copy>(
+        &mut self,
+        values: &[T],
+        value: impl Fn(T) -> FieldValue,
+        constraints: &[Box<dyn Constraint>],
+    ) -> Option<FieldValue> {
+        values
+            .iter()
+            .map(|v| value(*v))
+            .filter(|v| is_valid(constraints, v))
+            .choose(&mut self.rng)
+    }
+
+    fn integer(&mut self, field: &IntegerField) -> Option<FieldValue> {
+        let constraints = field.constrains();
+        if let Some(values) = &field.enumeration {
+            return self.choose(values, FieldValue::Integer, &constraints);
+        }
+        let lower = field
+            .minimum
+            .into_iter()
+            .chain(field.exclusive_minimum.map(|v| v.saturating_add(1)))
+            .max();
+        let upper = field
+            .maximum
+            .into_iter()
+            .chain(field.exclusive_maximum.map(|v| v.saturating_sub(1)))
+            .min();
+        let lower = lower.unwrap_or_else(|| upper.map_or(-SPAN, |u| u.saturating_sub(2 * SPAN)));
+        let upper = upper.unwrap_or_else(|| lower.saturating_add(2 * SPAN));
+        let value = FieldValue::Integer(self.rng.gen_range(lower..=upper.max(lower)));
+        is_valid(&constraints, &value).then_some(value)
+    }
+
+    fn uinteger(&mut self, field: &UIntegerField) -> Option<FieldValue> {
+        let constraints = field.constrains();
+        if let Some(values) = &field.enumeration {
+            return self.choose(values, FieldValue::UInteger, &constraints);
+        }
+        let lower = field
+            .minimum
+            .into_iter()
+            .chain(field.exclusive_minimum.map(|v| v.saturating_add(1)))
+            .max()
+            .unwrap_or(0);
+        let upper = field
+            .maximum
+            .into_iter()
+            .chain(field.exclusive_maximum.map(|v| v.saturating_sub(1)))
+            .min();
+        let upper = upper.unwrap_or_else(|| lower.saturating_add(2 * SPAN as u64));
+        let value = FieldValue::UInteger(self.rng.gen_range(lower..=upper.max(lower)));
+        is_valid(&constraints, &value).then_some(value)
+    }
+
+    fn float(&mut self, field: &FloatField) -> Option<FieldValue> {
+        let constraints = field.constrains();
+        if let Some(values) = &field.enumeration {
+            return self.choose(values, FieldValue::Float, &constraints);
+        }
+        let lower = [field.minimum, field.exclusive_minimum]
+            .into_iter()
+            .flatten()
+            .reduce(f64::max);
+        let upper = [field.maximum, field.exclusive_maximum]
+            .into_iter()
+            .flatten()
+            .reduce(f64::min);
+        let span = SPAN as f64;
+        let lower = lower.unwrap_or_else(|| upper.map_or(-span, |u| u - 2.0 * span));
+        let upper = upper.unwrap_or(lower + 2.0 * span);
+        if lower > upper {
+            return None;
+        }
+        // interpolated between the bounds, since the width of the range may overflow
+        let t: f64 = self.rng.gen();
+        let value = (lower * (1.0 - t) + upper * t).clamp(lower, upper);
+        // the middle of the range is only excluded by a range without any value
+        [value, lower / 2.0 + upper / 2.0]
+            .into_iter()
+            .map(FieldValue::Float)
+            .find(|v| is_valid(&constraints, v))
+    }
+
+    /// Retries until the string, with the transforms applied, satisfies the constraints.
+    fn string(&mut self, field: &StringField) -> Option<FieldValue> {
+        let constraints = field.constrains();
+        let transformed = |s: &str| match &field.transforms {
+            Some(transforms) => FieldValue::String(apply(transforms, s)),
+            None => FieldValue::String(s.to_string()),
+        };
+        if let Some(values) = &field.enumeration {
+            return values
+                .iter()
+                .filter(|v| is_valid(&constraints, &transformed(v)))
+                .choose(&mut self.rng)
+                .map(|v| FieldValue::String(v.clone()));
+        }
+        let pattern = field.pattern.as_ref().and_then(|p| {
+            let hir = regex_syntax::Parser::new().parse(p.pattern.as_str()).ok()?;
+            rand_regex::Regex::with_hir(unanchor(hir), 16).ok()
+        });
+        let min = field.min_length.unwrap_or(0);
+        let max = field.max_length.unwrap_or(min + 16).max(min);
+        let rng = &mut self.rng;
+        (0..ATTEMPTS)
+            .map(|_| match &pattern {
+                Some(pattern) => rng.sample::<String, _>(pattern),
+                None => {
+                    let len = rng.gen_range(min..=max);
+                    Alphanumeric.sample_string(rng, len)
+                }
+            })
+            .find(|candidate| is_valid(&constraints, &transformed(candidate)))
+            .map(FieldValue::String)
+    }
+}
+
+impl<R: Rng> ValueVisitor for Filler<'_, R> {
+    fn visit_array(&mut self, field: &ArrayField, value: &mut FieldValue, path: &Path) {
+        let len = match field.item {
+            Some(_) => self.len(path),
+            None => 0,
+        };
+        let mut values: Vec<FieldValue> = Vec::with_capacity(len);
+        for _ in 0..BATCHES {
+            if values.len() == len {
+                break;
+            }
+            let mut items = FieldValue::Array(vec![FieldValue::Null; len - values.len()]);
+            walk_array_value(self, field, &mut items, path);
+            let FieldValue::Array(items) = items else {
+                unreachable!()
+            };
+            for item in items {
+                if field.unique != Some(true) || !values.contains(&item) {
+                    values.push(item);
+                }
+            }
+        }
+        *value = FieldValue::Array(values);
+    }
+
+    fn visit_object(&mut self, field: &ObjectField, value: &mut FieldValue, path: &Path) {
+        let required = field.required.as_deref().unwrap_or_default();
+        if !required.is_empty() && path.depth() >= self.max_depth() {
+            return self.fail(path, "the required properties are nested too deep");
+        }
+        let deep = self.is_deep(path);
+        let mut values = BTreeMap::new();
+        for key in field.properties.keys() {
+            if required.contains(key) || (!deep && self.rng.gen_bool(0.5)) {
+                values.insert(key.clone(), FieldValue::Null);
+            }
+        }
+        if field.additional_properties.is_some() {
+            for _ in 0..self.len(path) {
+                let key = Alphanumeric.sample_string(&mut self.rng, 8);
+                if !field.properties.contains_key(&key) {
+                    values.insert(key, FieldValue::Null);
+                }
+            }
+        }
+        *value = FieldValue::Object(values);
+        walk_object_value(self, field, value, path)
+    }
+
+    fn visit_boolean(&mut self, _field: &BooleanField, value: &mut FieldValue, _path: &Path) {
+        *value = FieldValue::Boolean(self.rng.gen());
+    }
+
+    fn visit_float(&mut self, field: &FloatField, value: &mut FieldValue, path: &Path) {
+        let generated = self.float(field);
+        self.fill(value, generated, path)
+    }
+
+    fn visit_integer(&mut self, field: &IntegerField, value: &mut FieldValue, path: &Path) {
+        let generated = self.integer(field);
+        self.fill(value, generated, path)
+    }
+
+    fn visit_uinteger(&mut self, field: &UIntegerField, value: &mut FieldValue, path: &Path) {
+        let generated = self.uinteger(field);
+        self.fill(value, generated, path)
+    }
+
+    fn visit_string(&mut self, field: &StringField, value: &mut FieldValue, path: &Path) {
+        let generated = self.string(field);
+        self.fill(value, generated, path)
+    }
+
+    fn visit_email(&mut self, _field: &EmailField, value: &mut FieldValue, path: &Path) {
+        let email = format!("{}@{}.com", word(&mut self.rng), word(&mut self.rng));
+        let generated = EmailAddress::parse(&email, None).map(FieldValue::Email);
+        self.fill(value, generated, path)
+    }
+
+    fn visit_datetime(&mut self, _field: &DatetimeField, value: &mut FieldValue, path: &Path) {
+        let seconds = self.rng.gen_range(0..4_102_444_800);
+        let generated = DateTime::from_timestamp(seconds, 0).map(FieldValue::DateTime);
+        self.fill(value, generated, path)
+    }
+
+    fn visit_date(&mut self, _field: &DateField, value: &mut FieldValue, path: &Path) {
+        let days = self.rng.gen_range(0..36_525);
+        let generated = NaiveDate::from_ymd_opt(1970, 1, 1)
+            .and_then(|d| d.checked_add_days(chrono::Days::new(days)))
+            .map(FieldValue::Date);
+        self.fill(value, generated, path)
+    }
+
+    fn visit_time(&mut self, _field: &TimeField, value: &mut FieldValue, path: &Path) {
+        let seconds = self.rng.gen_range(0..86_400);
+        let generated =
+            NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0).map(FieldValue::Time);
+        self.fill(value, generated, path)
+    }
+
+    fn visit_unresolved(&mut self, field: &ReferenceField, _value: &mut FieldValue, path: &Path) {
+        self.fail(
+            path,
+            format!("reference {} is not resolved", field.reference),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::core::value::FieldValue;
+    use crate::visitor::generate::{Generator, GeneratorOptions};
+    use crate::visitor::validator::Validator;
+    use serde_json::json;
+
+    fn client_schema() -> FieldEnum {
+        serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "client",
+                "properties": {
+                    "id": {"type": "string", "name": "id", "pattern": "^[A-Z]{3}-[0-9]{4}$"},
+                    "name": {
+                        "type": "string",
+                        "name": "name",
+                        "minLength": 2,
+                        "maxLength": 8,
+                        "transforms": ["trim"]
+                    },
+                    "level": {"type": "string", "name": "level", "enum": ["basic", "premium"]},
+                    "age": {"type": "uinteger", "name": "age", "exclusiveMaximum": 200},
+                    "balance": {"type": "integer", "name": "balance", "minimum": -10, "maximum": 10},
+                    "ratio": {"type": "float", "name": "ratio", "exclusiveMinimum": 0.0, "exclusiveMaximum": 1.0},
+                    "score": {"type": "float", "name": "score", "enum": [0.5, 1.5]},
+                    "active": {"type": "boolean", "name": "active"},
+                    "email": {"type": "email", "name": "email"},
+                    "birthday": {"type": "date", "name": "birthday"},
+                    "wakeup": {"type": "time", "name": "wakeup"},
+                    "updated": {"type": "datetime", "name": "updated"},
+                    "tags": {
+                        "type": "array",
+                        "name": "tags",
+                        "item": {"type": "uinteger", "name": "tag", "maximum": 3},
+                        "unique": true
+                    },
+                    "labels": {
+                        "type": "object",
+                        "name": "labels",
+                        "properties": {},
+                        "additionalProperties": {"type": "string", "name": "label", "maxLength": 4}
+                    },
+                    "children": {
+                        "type": "array",
+                        "name": "children",
+                        "item": {"type": "reference", "name": "child", "reference": "client"}
+                    }
+                },
+                "required": ["id", "name", "level", "age", "email", "children"]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn field(value: serde_json::Value) -> FieldEnum {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_generate() {
+        let generator = Generator::new(client_schema());
+        let validator = Validator::new(client_schema());
+        for seed in 0..200 {
+            let value = generator.generate(seed).unwrap();
+            assert!(
+                validator.validate(&value).is_ok(),
+                "{:?}: {:?}",
+                value,
+                validator.validate(&value).err()
+            );
+            let FieldValue::Object(o) = &value else {
+                panic!("object is expected")
+            };
+            for key in ["id", "name", "level", "age", "email", "children"] {
+                assert!(o.contains_key(key));
+            }
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        let generator = Generator::new(client_schema());
+        assert_eq!(generator.generate(42), generator.generate(42));
+        assert!((0..10).any(|seed| generator.generate(seed) != generator.generate(42)));
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let generator = Generator::with_options(
+            client_schema(),
+            GeneratorOptions::new().max_depth(0).max_items(8),
+        );
+        let FieldValue::Object(o) = generator.generate(7).unwrap() else {
+            panic!("object is expected")
+        };
+        assert_eq!(o["children"], FieldValue::Array(vec![]));
+        assert_eq!(o.len(), 6);
+    }
+
+    #[test]
+    fn test_float_bounds() {
+        let extreme =
+            json!({"type": "float", "name": "ratio", "minimum": -1e308, "maximum": 1e308});
+        let validator = Validator::new(field(extreme.clone()));
+        let generator = Generator::new(field(extreme));
+        for seed in 0..50 {
+            assert!(validator.is_valid(&generator.generate(seed).unwrap()));
+        }
+
+        let adjacent = Generator::new(field(json!({
+            "type": "float",
+            "name": "ratio",
+            "minimum": 1.0,
+            "exclusiveMaximum": 1.0000000000000002
+        })));
+        for seed in 0..50 {
+            assert_eq!(adjacent.generate(seed).unwrap(), FieldValue::Float(1.0));
+        }
+
+        let empty = Generator::new(field(json!({
+            "type": "float",
+            "name": "ratio",
+            "exclusiveMinimum": 1.0,
+            "exclusiveMaximum": 1.0000000000000002
+        })));
+        let error = empty.generate(0).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "can not generate a value of ratio: no value satisfies the constraints"
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        for pattern in ["^a|b$", "\\$[0-9]+$", "^(?:ab|cd)+\\b"] {
+            let schema = json!({"type": "string", "name": "code", "pattern": pattern});
+            let validator = Validator::new(field(schema.clone()));
+            let generator = Generator::new(field(schema));
+            for seed in 0..20 {
+                let value = generator.generate(seed).unwrap();
+                assert!(validator.is_valid(&value), "{}: {:?}", pattern, value);
+            }
+        }
+
+        let unsatisfiable = Generator::new(field(json!({
+            "type": "string",
+            "name": "code",
+            "pattern": "^[a-z]{4}$",
+            "maxLength": 2
+        })));
+        assert_eq!(unsatisfiable.generate(0).unwrap_err().path, "code");
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_strategy(value in Generator::new(client_schema()).into_strategy()) {
+            proptest::prop_assert!(Validator::new(client_schema()).validate(&value).is_ok());
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::core::field::date::DateField;
+use crate::core::field::datetime::DatetimeField;
+use crate::core::field::email::EmailField;
+use crate::core::field::float::FloatField;
+use crate::core::field::integer::IntegerField;
+use crate::core::field::time::TimeField;
+use crate::core::field::FieldEnum;
+use crate::core::value::FieldValue;
+use crate::visitor::walk::{walk_with_value, Path, ValueVisitor};
+use chrono::{DateTime, NaiveDate, NaiveTime, SecondsFormat, Utc};
+use email_address_parser::EmailAddress;
+use serde_json::{Number, Value};
+
+fn convert(value: &Value) -> FieldValue {
+    match value {
+        Value::Null => FieldValue::Null,
+        Value::Bool(v) => FieldValue::Boolean(*v),
+        Value::Number(n) => {
+            if let Some(v) = n.as_u64() {
+                FieldValue::UInteger(v)
+            } else if let Some(v) = n.as_i64() {
+                FieldValue::Integer(v)
+            } else {
+                FieldValue::Float(n.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Value::String(s) => FieldValue::String(s.clone()),
+        Value::Array(values) => FieldValue::Array(values.iter().map(convert).collect()),
+        Value::Object(o) => {
+            FieldValue::Object(o.iter().map(|(k, v)| (k.clone(), convert(v))).collect())
+        }
+    }
+}
+
+fn parse(value: &mut FieldValue, parse: impl FnOnce(&str) -> Option<FieldValue>) {
+    let parsed = match value {
+        FieldValue::String(s) => parse(s),
+        _ => None,
+    };
+    if let Some(parsed) = parsed {
+        *value = parsed;
+    }
+}
+
+struct Typed;
+
+impl ValueVisitor for Typed {
+    fn visit_float(&mut self, _field: &FloatField, value: &mut FieldValue, _path: &Path) {
+        match *value {
+            FieldValue::UInteger(v) => *value = FieldValue::Float(v as f64),
+            FieldValue::Integer(v) => *value = FieldValue::Float(v as f64),
+            _ => {}
+        }
+    }
+
+    fn visit_integer(&mut self, _field: &IntegerField, value: &mut FieldValue, _path: &Path) {
+        if let FieldValue::UInteger(v) = *value {
+            if let Ok(v) = i64::try_from(v) {
+                *value = FieldValue::Integer(v);
+            }
+        }
+    }
+
+    fn visit_email(&mut self, _field: &EmailField, value: &mut FieldValue, _path: &Path) {
+        parse(value, |s| {
+            EmailAddress::parse(s, None).map(FieldValue::Email)
+        })
+    }
+
+    fn visit_datetime(&mut self, _field: &DatetimeField, value: &mut FieldValue, _path: &Path) {
+        parse(value, |s| {
+            DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|v| FieldValue::DateTime(v.with_timezone(&Utc)))
+        })
+    }
+
+    fn visit_date(&mut self, _field: &DateField, value: &mut FieldValue, _path: &Path) {
+        parse(value, |s| s.parse::<NaiveDate>().ok().map(FieldValue::Date))
+    }
+
+    fn visit_time(&mut self, _field: &TimeField, value: &mut FieldValue, _path: &Path) {
+        parse(value, |s| s.parse::<NaiveTime>().ok().map(FieldValue::Time))
+    }
+}
+
+/// Converts a JSON value into [FieldValue] by the schema, e.g. a string into [FieldValue::Email]
+/// for an email field. The values which can not be converted are kept as is.
+pub fn from_json(schema: &FieldEnum, value: &Value) -> FieldValue {
+    let mut value = convert(value);
+    walk_with_value(&mut Typed, schema, &mut value);
+    value
+}
+
+/// Converts [FieldValue] into a JSON value, the floats which are not finite into null.
+pub fn to_json(value: &FieldValue) -> Value {
+    match value {
+        FieldValue::Null => Value::Null,
+        FieldValue::Boolean(v) => Value::Bool(*v),
+        FieldValue::Integer(v) => Value::from(*v),
+        FieldValue::UInteger(v) => Value::from(*v),
+        FieldValue::Float(v) => Number::from_f64(*v).map_or(Value::Null, Value::Number),
+        FieldValue::String(v) => Value::String(v.clone()),
+        FieldValue::Email(v) => Value::String(v.to_string()),
+        FieldValue::DateTime(v) => Value::String(v.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+        FieldValue::Date(v) => Value::String(v.to_string()),
+        FieldValue::Time(v) => Value::String(v.to_string()),
+        FieldValue::Array(values) => Value::Array(values.iter().map(to_json).collect()),
+        FieldValue::Object(values) => Value::Object(
+            values
+                .iter()
+                .map(|(k, v)| (k.clone(), to_json(v)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::core::value::FieldValue;
+    use crate::visitor::json::{from_json, to_json};
+    use crate::visitor::validator::Validator;
+    use chrono::NaiveDate;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    fn client_schema() -> FieldEnum {
+        serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "client",
+                "properties": {
+                    "age": {
+                        "type": "integer",
+                        "name": "age"
+                    },
+                    "email": {
+                        "type": "email",
+                        "name": "email"
+                    },
+                    "birthday": {
+                        "type": "date",
+                        "name": "birthday"
+                    },
+                    "friends": {
+                        "type": "array",
+                        "name": "friends",
+                        "item": {
+                            "type": "reference",
+                            "name": "friend",
+                            "reference": "client"
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_json() {
+        let value = from_json(
+            &client_schema(),
+            &json!({
+                "age": 32,
+                "email": "admin@avocado.com",
+                "birthday": "1991-09-19",
+                "friends": [{"age": 30, "birthday": "19 Sep 1991"}],
+                "nickname": "Bob"
+            }),
+        );
+        let FieldValue::Object(o) = value else {
+            panic!("object is expected")
+        };
+        assert_eq!(o["age"], FieldValue::Integer(32));
+        assert!(matches!(o["email"], FieldValue::Email(_)));
+        assert_eq!(
+            o["birthday"],
+            FieldValue::Date(NaiveDate::from_ymd_opt(1991, 9, 19).unwrap())
+        );
+        assert_eq!(
+            o["friends"],
+            FieldValue::Array(vec![FieldValue::Object(BTreeMap::from([
+                ("age".to_string(), FieldValue::Integer(30)),
+                (
+                    "birthday".to_string(),
+                    FieldValue::String("19 Sep 1991".to_string())
+                )
+            ]))])
+        );
+        assert_eq!(o["nickname"], FieldValue::String("Bob".to_string()));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json = json!({
+            "age": 32,
+            "email": "admin@avocado.com",
+            "birthday": "1991-09-19",
+            "friends": [{"age": 30, "birthday": "19 Sep 1991"}],
+            "nickname": null
+        });
+        assert_eq!(to_json(&from_json(&client_schema(), &json)), json);
+        assert_eq!(
+            to_json(&FieldValue::Float(f64::NAN)),
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let schema = client_schema();
+        let valid = from_json(&schema, &json!({"age": 32, "email": "admin@avocado.com"}));
+        let invalid = from_json(&schema, &json!({"age": 32, "email": "admin"}));
+
+        let validator = Validator::new(schema);
+        assert!(validator.validate(&valid).is_ok());
+        assert!(validator
+            .validate(&invalid)
+            .err()
+            .unwrap()
+            .contains_key("client/email"));
+    }
+}
@@ -0,0 +1,589 @@
+use crate::core::constraint::Constraint;
+use crate::core::field::{Field, FieldEnum};
+use crate::core::transform::apply;
+use crate::core::value::FieldValue;
+use crate::visitor::json::from_json;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    /// The schema contradicts itself, so (part of) it can never be satisfied.
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// An issue found in the schema, located by the names of the fields joined by `/`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    pub severity: Severity,
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.path, self.message)
+    }
+}
+
+struct State<'a> {
+    field_names: Vec<String>,
+    ancestors: Vec<&'a str>,
+    issues: Vec<Issue>,
+}
+
+impl State<'_> {
+    fn report(&mut self, severity: Severity, message: String) {
+        self.issues.push(Issue {
+            severity,
+            path: self.field_names.join("/"),
+            message,
+        })
+    }
+}
+
+fn check_range<T: PartialOrd + Display + Copy>(
+    minimum: Option<T>,
+    exclusive_minimum: Option<T>,
+    maximum: Option<T>,
+    exclusive_maximum: Option<T>,
+    state: &mut State,
+) {
+    let lowers = [
+        ("minimum", minimum, false),
+        ("exclusiveMinimum", exclusive_minimum, true),
+    ];
+    let uppers = [
+        ("maximum", maximum, false),
+        ("exclusiveMaximum", exclusive_maximum, true),
+    ];
+    for (lower_name, lower, lower_exclusive) in lowers {
+        for (upper_name, upper, upper_exclusive) in uppers {
+            let (Some(lower), Some(upper)) = (lower, upper) else {
+                continue;
+            };
+            let empty = if lower_exclusive || upper_exclusive {
+                lower >= upper
+            } else {
+                lower > upper
+            };
+            if empty {
+                state.report(
+                    Severity::Error,
+                    format!(
+                        "{} {} and {} {} can not be satisfied by any value",
+                        lower_name, lower, upper_name, upper
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn check_enum<T: Display + PartialEq>(
+    values: Option<&Vec<T>>,
+    value: impl Fn(&T) -> FieldValue,
+    constraints: Vec<Box<dyn Constraint>>,
+    state: &mut State,
+) {
+    let Some(values) = values else {
+        return;
+    };
+    if values.is_empty() {
+        state.report(
+            Severity::Error,
+            "enum is empty, so no value is valid".to_string(),
+        );
+        return;
+    }
+
+    let mut invalid = 0;
+    for (i, v) in values.iter().enumerate() {
+        if values[..i].contains(v) {
+            state.report(Severity::Warning, format!("enum value {} is duplicated", v));
+        }
+        let field_value = value(v);
+        if let Some(e) = constraints
+            .iter()
+            .find_map(|c| c.validate(&field_value).err())
+        {
+            invalid += 1;
+            state.report(
+                Severity::Warning,
+                format!("enum value {} can never be valid: {}", v, e),
+            );
+        }
+    }
+    if invalid == values.len() {
+        state.report(
+            Severity::Error,
+            "none of the enum values satisfies the other constraints".to_string(),
+        );
+    }
+}
+
+fn check_default(field: &FieldEnum, state: &mut State) {
+    let Some(default) = &field.metadata().default else {
+        return;
+    };
+    let value = from_json(field, default);
+    if let Some(e) = field
+        .constrains()
+        .iter()
+        .find_map(|c| c.validate(&value).err())
+    {
+        state.report(
+            Severity::Error,
+            format!("default {} is not a valid value: {}", default, e),
+        );
+    }
+}
+
+fn check_field<'a>(field: &'a FieldEnum, state: &mut State<'a>) {
+    check_default(field, state);
+    match field {
+        FieldEnum::Integer(f) => {
+            check_range(
+                f.minimum,
+                f.exclusive_minimum,
+                f.maximum,
+                f.exclusive_maximum,
+                state,
+            );
+            check_enum(
+                f.enumeration.as_ref(),
+                |v| FieldValue::Integer(*v),
+                f.constrains(),
+                state,
+            );
+        }
+        FieldEnum::UInteger(f) => {
+            check_range(
+                f.minimum,
+                f.exclusive_minimum,
+                f.maximum,
+                f.exclusive_maximum,
+                state,
+            );
+            check_enum(
+                f.enumeration.as_ref(),
+                |v| FieldValue::UInteger(*v),
+                f.constrains(),
+                state,
+            );
+        }
+        FieldEnum::Float(f) => {
+            let bounds = [
+                ("minimum", f.minimum),
+                ("exclusiveMinimum", f.exclusive_minimum),
+                ("maximum", f.maximum),
+                ("exclusiveMaximum", f.exclusive_maximum),
+            ];
+            for (name, bound) in bounds {
+                if bound.is_some_and(f64::is_nan) {
+                    state.report(Severity::Error, format!("{} is not a number", name));
+                }
+            }
+            check_range(
+                f.minimum,
+                f.exclusive_minimum,
+                f.maximum,
+                f.exclusive_maximum,
+                state,
+            );
+            check_enum(
+                f.enumeration.as_ref(),
+                |v| FieldValue::Float(*v),
+                f.constrains(),
+                state,
+            );
+        }
+        FieldEnum::String(f) => {
+            if let (Some(min), Some(max)) = (f.min_length, f.max_length) {
+                if min > max {
+                    state.report(
+                        Severity::Error,
+                        format!(
+                            "minLength {} is larger than maxLength {}, so no value is valid",
+                            min, max
+                        ),
+                    );
+                }
+            }
+            check_enum(
+                f.enumeration.as_ref(),
+                |v| FieldValue::String(v.clone()),
+                f.constrains(),
+                state,
+            );
+            let transforms = f.transforms.as_deref().unwrap_or_default();
+            for v in f.enumeration.iter().flatten() {
+                if !transforms.is_empty() && apply(transforms, v) != *v {
+                    state.report(
+                        Severity::Warning,
+                        format!(
+                            "enum value {} is changed by the transforms, so it can never be matched",
+                            v
+                        ),
+                    );
+                }
+            }
+        }
+        FieldEnum::Array(f) => {
+            state.ancestors.push(&f.name);
+            if let Some(item) = &f.item {
+                state.field_names.push(item.name());
+                check_field(item, state);
+                state.field_names.pop();
+            }
+            state.ancestors.pop();
+        }
+        FieldEnum::Object(f) => {
+            let mut required = BTreeSet::new();
+            for name in f.required.iter().flatten() {
+                if !required.insert(name) {
+                    state.report(
+                        Severity::Warning,
+                        format!("required field {} is listed more than once", name),
+                    );
+                }
+                if !f.properties.contains_key(name) {
+                    state.report(
+                        Severity::Error,
+                        format!("required field {} is not defined in properties", name),
+                    );
+                }
+            }
+
+            state.ancestors.push(&f.name);
+            for (key, property) in &f.properties {
+                let name = property.name();
+                if key != &name {
+                    state.report(
+                        Severity::Warning,
+                        format!(
+                            "property key {} differs from the name {} of the field",
+                            key, name
+                        ),
+                    );
+                }
+                state.field_names.push(name);
+                check_field(property, state);
+                state.field_names.pop();
+            }
+            if let Some(additional) = &f.additional_properties {
+                state.field_names.push(additional.name());
+                check_field(additional, state);
+                state.field_names.pop();
+            }
+            state.ancestors.pop();
+        }
+        FieldEnum::Reference(f) => {
+            if !state.ancestors.contains(&f.reference.as_str()) {
+                state.report(
+                    Severity::Error,
+                    format!(
+                        "reference {} does not refer to any enclosing array or object",
+                        f.reference
+                    ),
+                );
+            }
+        }
+        FieldEnum::Boolean(_)
+        | FieldEnum::Email(_)
+        | FieldEnum::Datetime(_)
+        | FieldEnum::Date(_)
+        | FieldEnum::Time(_) => {}
+    }
+}
+
+/// Checks the schema is consistent with itself, e.g. the minimum of a field is not larger than
+/// its maximum.
+pub fn lint(schema: &FieldEnum) -> Vec<Issue> {
+    let mut state = State {
+        field_names: vec![schema.name()],
+        ancestors: vec![],
+        issues: vec![],
+    };
+    check_field(schema, &mut state);
+    state.issues
+}
+
+/// Same as [lint], but only fails if any error (rather than warning) is found.
+pub fn check(schema: &FieldEnum) -> Result<Vec<Issue>, Vec<Issue>> {
+    let issues = lint(schema);
+    if issues.iter().any(|i| i.severity == Severity::Error) {
+        Err(issues)
+    } else {
+        Ok(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::array::ArrayFieldBuilder;
+    use crate::core::field::integer::IntegerFieldBuilder;
+    use crate::core::field::object::ObjectFieldBuilder;
+    use crate::core::field::reference::ReferenceFieldBuilder;
+    use crate::core::field::string::StringFieldBuilder;
+    use crate::core::field::FieldEnum;
+    use crate::core::transform::Transform;
+    use crate::visitor::lint::{check, lint, Severity};
+
+    fn issues(schema: &FieldEnum) -> Vec<(Severity, String, String)> {
+        lint(schema)
+            .into_iter()
+            .map(|i| (i.severity, i.path, i.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_clean() {
+        let schema: FieldEnum = ObjectFieldBuilder::new()
+            .name("client")
+            .property(
+                "name",
+                StringFieldBuilder::new()
+                    .name("name")
+                    .min_length(1)
+                    .max_length(8)
+                    .build(),
+            )
+            .required(vec!["name".to_string()])
+            .build()
+            .into();
+        assert!(lint(&schema).is_empty());
+        assert!(check(&schema).is_ok());
+    }
+
+    #[test]
+    fn test_range() {
+        let schema: FieldEnum = serde_json::from_str(
+            r#"
+            {
+                "type": "integer",
+                "name": "age",
+                "minimum": 10,
+                "maximum": 5,
+                "exclusiveMaximum": 10
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            issues(&schema),
+            vec![
+                (
+                    Severity::Error,
+                    "age".to_string(),
+                    "minimum 10 and maximum 5 can not be satisfied by any value".to_string()
+                ),
+                (
+                    Severity::Error,
+                    "age".to_string(),
+                    "minimum 10 and exclusiveMaximum 10 can not be satisfied by any value"
+                        .to_string()
+                ),
+            ]
+        );
+
+        let schema: FieldEnum = serde_json::from_str(
+            r#"{"type": "float", "name": "ratio", "minimum": 1.5, "maximum": 1.5}"#,
+        )
+        .unwrap();
+        assert!(lint(&schema).is_empty());
+    }
+
+    #[test]
+    fn test_length() {
+        let schema: FieldEnum = StringFieldBuilder::new()
+            .name("name")
+            .min_length(8)
+            .max_length(4)
+            .build()
+            .into();
+        let issues = check(&schema).err().unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].to_string(),
+            "error: name: minLength 8 is larger than maxLength 4, so no value is valid"
+        );
+    }
+
+    #[test]
+    fn test_enum() {
+        let schema: FieldEnum =
+            serde_json::from_str(r#"{"type": "string", "name": "status", "enum": []}"#).unwrap();
+        assert_eq!(
+            issues(&schema),
+            vec![(
+                Severity::Error,
+                "status".to_string(),
+                "enum is empty, so no value is valid".to_string()
+            )]
+        );
+
+        let schema: FieldEnum = StringFieldBuilder::new()
+            .name("status")
+            .enumeration(vec![
+                "active".to_string(),
+                "inactive".to_string(),
+                "active".to_string(),
+            ])
+            .max_length(6)
+            .build()
+            .into();
+        let issues = check(&schema).unwrap();
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0]
+            .message
+            .starts_with("enum value inactive can never be valid: "));
+        assert!(issues[0].message.ends_with("(MaxLength)"));
+        assert_eq!(issues[1].message, "enum value active is duplicated");
+
+        let schema: FieldEnum = IntegerFieldBuilder::new()
+            .name("level")
+            .enumeration(vec![10, 20])
+            .maximum(5)
+            .build()
+            .into();
+        let issues = check(&schema).err().unwrap();
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[2].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let schema: FieldEnum = StringFieldBuilder::new()
+            .name("status")
+            .enumeration(vec!["active".to_string(), "Inactive".to_string()])
+            .transforms(vec![Transform::Trim, Transform::Lowercase])
+            .build()
+            .into();
+        assert_eq!(
+            issues(&schema),
+            vec![(
+                Severity::Warning,
+                "status".to_string(),
+                "enum value Inactive is changed by the transforms, so it can never be matched"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_object() {
+        let schema: FieldEnum = ObjectFieldBuilder::new()
+            .name("client")
+            .property("first_name", StringFieldBuilder::new().name("name").build())
+            .property(
+                "tags",
+                ArrayFieldBuilder::new()
+                    .name("tags")
+                    .item(
+                        StringFieldBuilder::new()
+                            .name("tag")
+                            .enumeration(vec![])
+                            .build(),
+                    )
+                    .build(),
+            )
+            .required(vec![
+                "first_name".to_string(),
+                "last_name".to_string(),
+                "first_name".to_string(),
+            ])
+            .build()
+            .into();
+        assert_eq!(
+            issues(&schema),
+            vec![
+                (
+                    Severity::Error,
+                    "client".to_string(),
+                    "required field last_name is not defined in properties".to_string()
+                ),
+                (
+                    Severity::Warning,
+                    "client".to_string(),
+                    "required field first_name is listed more than once".to_string()
+                ),
+                (
+                    Severity::Warning,
+                    "client".to_string(),
+                    "property key first_name differs from the name name of the field".to_string()
+                ),
+                (
+                    Severity::Error,
+                    "client/tags/tag".to_string(),
+                    "enum is empty, so no value is valid".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reference() {
+        let schema: FieldEnum = ArrayFieldBuilder::new()
+            .name("children")
+            .item(
+                ReferenceFieldBuilder::new()
+                    .name("child")
+                    .reference("category")
+                    .build(),
+            )
+            .build()
+            .into();
+        assert_eq!(
+            issues(&schema),
+            vec![(
+                Severity::Error,
+                "children/child".to_string(),
+                "reference category does not refer to any enclosing array or object".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        let schema: FieldEnum = serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "client",
+                "properties": {
+                    "nickname": {"type": "string", "name": "nickname", "maxLength": 4, "default": "Anonymous"},
+                    "age": {"type": "uinteger", "name": "age", "default": "unknown"},
+                    "level": {"type": "string", "name": "level", "enum": ["basic", "premium"], "default": "basic"},
+                    "updated": {"type": "datetime", "name": "updated", "default": "2023-09-19T07:30:00Z"}
+                }
+            }"#,
+        )
+        .unwrap();
+        let issues = issues(&schema);
+        assert_eq!(
+            issues
+                .iter()
+                .map(|(severity, path, _)| (*severity, path.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Severity::Error, "client/age"),
+                (Severity::Error, "client/nickname"),
+            ]
+        );
+        assert!(issues[0]
+            .2
+            .starts_with("default \"unknown\" is not a valid value"));
+        assert!(issues[1].2.ends_with("(MaxLength)"));
+    }
+}
@@ -0,0 +1,530 @@
+use crate::core::field::FieldEnum;
+use crate::core::value::FieldValue;
+use crate::visitor::json::{from_json, to_json};
+use crate::visitor::validator::{ValidationError, Validator};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// A declarative rule of a migration. The paths are the keys joined by `/`, where `*` stands
+/// for every item of an array (or value of an object), e.g. `addresses/*/street`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Rule {
+    Rename {
+        path: String,
+        to: String,
+    },
+    /// The objects on the way to the new path are created if missing.
+    Move {
+        from: String,
+        to: String,
+    },
+    Remove {
+        path: String,
+    },
+    /// Adds the value at the path if it is missing (or null).
+    Add {
+        path: String,
+        value: Value,
+    },
+    /// The last key takes the rest of the string.
+    Split {
+        path: String,
+        separator: String,
+        into: Vec<String>,
+    },
+    /// The values are compared as JSON, the values not in the mappings are kept.
+    Map {
+        path: String,
+        values: Vec<Mapping>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mapping {
+    pub from: Value,
+    pub to: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Migration {
+    pub from: u32,
+    pub to: u32,
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    #[error("no schema of version {0}")]
+    UnknownVersion(u32),
+    #[error("no migration from version {0}")]
+    MissingMigration(u32),
+    #[error("migration from version {from} goes back to version {to}")]
+    Backwards { from: u32, to: u32 },
+    #[error("can not migrate from version {from} down to version {to}")]
+    Downgrade { from: u32, to: u32 },
+    #[error("migration from version {from} goes past version {target} to version {to}")]
+    Overshoot { from: u32, to: u32, target: u32 },
+    #[error("migration from version {from} failed: {message}")]
+    Rule { from: u32, message: String },
+    #[error("migrated document does not match the schema of version {version}")]
+    Invalid {
+        version: u32,
+        errors: BTreeMap<String, Vec<ValidationError>>,
+    },
+}
+
+fn segments(path: &str) -> Vec<&str> {
+    path.split('/').filter(|s| !s.is_empty()).collect()
+}
+
+fn select<'a>(value: &'a mut Value, segments: &[&str]) -> Vec<&'a mut Value> {
+    let Some((first, rest)) = segments.split_first() else {
+        return vec![value];
+    };
+    match (value, *first) {
+        (Value::Array(values), "*") => values.iter_mut().flat_map(|v| select(v, rest)).collect(),
+        (Value::Object(values), "*") => values.values_mut().flat_map(|v| select(v, rest)).collect(),
+        (Value::Object(values), key) => match values.get_mut(key) {
+            Some(v) => select(v, rest),
+            None => vec![],
+        },
+        (Value::Array(values), index) => match index.parse::<usize>().ok() {
+            Some(index) => values
+                .get_mut(index)
+                .map(|v| select(v, rest))
+                .unwrap_or_default(),
+            None => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+fn parents<'a, 'p>(
+    value: &'a mut Value,
+    path: &'p str,
+) -> (Vec<&'a mut Map<String, Value>>, &'p str) {
+    let segments = segments(path);
+    let Some((key, parents)) = segments.split_last() else {
+        return (vec![], "");
+    };
+    let objects = select(value, parents)
+        .into_iter()
+        .filter_map(|v| v.as_object_mut())
+        .collect();
+    (objects, key)
+}
+
+fn apply(rule: &Rule, document: &mut Value) -> Result<(), String> {
+    match rule {
+        Rule::Rename { path, to } => {
+            let (objects, key) = parents(document, path);
+            for object in objects {
+                if let Some(value) = object.remove(key) {
+                    object.insert(to.clone(), value);
+                }
+            }
+        }
+        Rule::Move { from, to } => {
+            if from.contains('*') || to.contains('*') {
+                return Err(format!("can not move {} to {} by *", from, to));
+            }
+            let (objects, key) = parents(document, from);
+            let Some(value) = objects.into_iter().find_map(|o| o.remove(key)) else {
+                return Ok(());
+            };
+            let segments = segments(to);
+            let Some((key, parents)) = segments.split_last() else {
+                return Err("can not move to the root".to_string());
+            };
+            let mut object = document;
+            for parent in parents {
+                let Value::Object(values) = object else {
+                    return Err(format!("{} is not in an object", to));
+                };
+                object = values
+                    .entry(parent.to_string())
+                    .or_insert_with(|| Value::Object(Map::new()));
+            }
+            match object {
+                Value::Object(values) => {
+                    values.insert(key.to_string(), value);
+                }
+                _ => return Err(format!("{} is not in an object", to)),
+            }
+        }
+        Rule::Remove { path } => {
+            let (objects, key) = parents(document, path);
+            for object in objects {
+                object.remove(key);
+            }
+        }
+        Rule::Add { path, value } => {
+            let (objects, key) = parents(document, path);
+            for object in objects {
+                if matches!(object.get(key), None | Some(Value::Null)) {
+                    object.insert(key.to_string(), value.clone());
+                }
+            }
+        }
+        Rule::Split {
+            path,
+            separator,
+            into,
+        } => {
+            if into.is_empty() {
+                return Err(format!("can not split {} into no keys", path));
+            }
+            let (objects, key) = parents(document, path);
+            for object in objects {
+                let s = match object.get(key) {
+                    None | Some(Value::Null) => continue,
+                    Some(Value::String(s)) => s.clone(),
+                    Some(v) => return Err(format!("can not split {} which is not a string", v)),
+                };
+                object.remove(key);
+                for (key, part) in into.iter().zip(s.splitn(into.len(), separator.as_str())) {
+                    object.insert(key.clone(), Value::String(part.to_string()));
+                }
+            }
+        }
+        Rule::Map { path, values } => {
+            for value in select(document, &segments(path)) {
+                if let Some(mapping) = values.iter().find(|m| m.from == *value) {
+                    *value = mapping.to.clone();
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Migrates the documents between the versions of a schema, one version at a time.
+#[derive(Debug, Default)]
+pub struct Migrator {
+    validators: BTreeMap<u32, Validator>,
+    migrations: BTreeMap<u32, Migration>,
+}
+
+impl Migrator {
+    pub fn new() -> Self {
+        Migrator::default()
+    }
+
+    pub fn schema(mut self, version: u32, schema: impl Into<FieldEnum>) -> Self {
+        self.validators.insert(version, Validator::new(schema));
+        self
+    }
+
+    pub fn migration(mut self, migration: Migration) -> Self {
+        self.migrations.insert(migration.from, migration);
+        self
+    }
+
+    /// Validates the migrated document against the schema of the target version.
+    pub fn migrate(
+        &self,
+        document: &FieldValue,
+        from: u32,
+        to: u32,
+    ) -> Result<FieldValue, MigrationError> {
+        self.migrate_json(&to_json(document), from, to)
+    }
+
+    pub fn migrate_json(
+        &self,
+        document: &Value,
+        from: u32,
+        to: u32,
+    ) -> Result<FieldValue, MigrationError> {
+        if from > to {
+            return Err(MigrationError::Downgrade { from, to });
+        }
+        let validator = self
+            .validators
+            .get(&to)
+            .ok_or(MigrationError::UnknownVersion(to))?;
+        let mut document = document.clone();
+        let mut version = from;
+        while version < to {
+            let migration = self
+                .migrations
+                .get(&version)
+                .ok_or(MigrationError::MissingMigration(version))?;
+            if migration.to <= version {
+                return Err(MigrationError::Backwards {
+                    from: version,
+                    to: migration.to,
+                });
+            }
+            if migration.to > to {
+                return Err(MigrationError::Overshoot {
+                    from: version,
+                    to: migration.to,
+                    target: to,
+                });
+            }
+            for rule in &migration.rules {
+                apply(rule, &mut document).map_err(|message| MigrationError::Rule {
+                    from: version,
+                    message,
+                })?;
+            }
+            version = migration.to;
+        }
+
+        let document = from_json(validator.schema(), &document);
+        validator
+            .validate(&document)
+            .map_err(|errors| MigrationError::Invalid {
+                version: to,
+                errors,
+            })?;
+        Ok(document)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::visitor::json::{from_json, to_json};
+    use crate::visitor::migrate::{apply, Mapping, Migration, MigrationError, Migrator, Rule};
+    use serde_json::json;
+
+    fn v1() -> FieldEnum {
+        serde_json::from_value(json!({
+            "type": "object",
+            "name": "client",
+            "properties": {
+                "name": {"type": "string", "name": "name"},
+                "level": {"type": "string", "name": "level", "enum": ["basic", "gold"]},
+                "street": {"type": "string", "name": "street"},
+                "phones": {
+                    "type": "array",
+                    "name": "phones",
+                    "item": {
+                        "type": "object",
+                        "name": "phone",
+                        "properties": {"no": {"type": "string", "name": "no"}}
+                    }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn v2() -> FieldEnum {
+        serde_json::from_value(json!({
+            "type": "object",
+            "name": "client",
+            "properties": {
+                "first_name": {"type": "string", "name": "first_name"},
+                "last_name": {"type": "string", "name": "last_name"},
+                "level": {"type": "string", "name": "level", "enum": ["basic", "premium"]},
+                "address": {
+                    "type": "object",
+                    "name": "address",
+                    "properties": {"street": {"type": "string", "name": "street"}}
+                },
+                "phones": {
+                    "type": "array",
+                    "name": "phones",
+                    "item": {
+                        "type": "object",
+                        "name": "phone",
+                        "properties": {"number": {"type": "string", "name": "number"}}
+                    }
+                }
+            },
+            "required": ["first_name", "last_name"]
+        }))
+        .unwrap()
+    }
+
+    fn v3() -> FieldEnum {
+        serde_json::from_value(json!({
+            "type": "object",
+            "name": "client",
+            "properties": {
+                "first_name": {"type": "string", "name": "first_name"},
+                "last_name": {"type": "string", "name": "last_name"},
+                "level": {"type": "string", "name": "level", "enum": ["basic", "premium"]},
+                "active": {"type": "boolean", "name": "active"}
+            },
+            "required": ["first_name", "last_name", "active"]
+        }))
+        .unwrap()
+    }
+
+    fn migrator() -> Migrator {
+        let v1_v2: Migration = serde_json::from_value(json!({
+            "from": 1,
+            "to": 2,
+            "rules": [
+                {"op": "split", "path": "name", "separator": " ", "into": ["first_name", "last_name"]},
+                {"op": "map", "path": "level", "values": [{"from": "gold", "to": "premium"}]},
+                {"op": "move", "from": "street", "to": "address/street"},
+                {"op": "rename", "path": "phones/*/no", "to": "number"}
+            ]
+        }))
+        .unwrap();
+        Migrator::new()
+            .schema(1, v1())
+            .schema(2, v2())
+            .schema(3, v3())
+            .migration(v1_v2)
+            .migration(Migration {
+                from: 2,
+                to: 3,
+                rules: vec![
+                    Rule::Remove {
+                        path: "address".to_string(),
+                    },
+                    Rule::Remove {
+                        path: "phones".to_string(),
+                    },
+                    Rule::Add {
+                        path: "active".to_string(),
+                        value: json!(true),
+                    },
+                ],
+            })
+    }
+
+    fn document() -> serde_json::Value {
+        json!({
+            "name": "Robert Li Jr",
+            "level": "gold",
+            "street": "1 Avocado St",
+            "phones": [{"no": "123"}, {"no": "456"}]
+        })
+    }
+
+    #[test]
+    fn test_migrate() {
+        let migrated = migrator()
+            .migrate(&from_json(&v1(), &document()), 1, 2)
+            .unwrap();
+        assert_eq!(
+            to_json(&migrated),
+            json!({
+                "first_name": "Robert",
+                "last_name": "Li Jr",
+                "level": "premium",
+                "address": {"street": "1 Avocado St"},
+                "phones": [{"number": "123"}, {"number": "456"}]
+            })
+        );
+    }
+
+    #[test]
+    fn test_chain() {
+        let migrated = migrator().migrate_json(&document(), 1, 3).unwrap();
+        assert_eq!(
+            to_json(&migrated),
+            json!({
+                "first_name": "Robert",
+                "last_name": "Li Jr",
+                "level": "premium",
+                "active": true
+            })
+        );
+        let same = migrator().migrate_json(&to_json(&migrated), 3, 3).unwrap();
+        assert_eq!(same, migrated);
+    }
+
+    #[test]
+    fn test_errors() {
+        let migrator = migrator();
+        assert!(matches!(
+            migrator.migrate_json(&document(), 1, 4),
+            Err(MigrationError::UnknownVersion(4))
+        ));
+        assert!(matches!(
+            migrator.migrate_json(&document(), 0, 3),
+            Err(MigrationError::MissingMigration(0))
+        ));
+
+        let Err(MigrationError::Invalid { version, errors }) =
+            migrator.migrate_json(&json!({"name": "Robert"}), 1, 3)
+        else {
+            panic!("invalid document is expected")
+        };
+        assert_eq!(version, 3);
+        assert!(errors.contains_key("client"));
+
+        assert!(matches!(
+            migrator.migrate_json(&json!({"name": 42}), 1, 2),
+            Err(MigrationError::Rule { from: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_versions() {
+        let error = migrator().migrate_json(&document(), 3, 1).err().unwrap();
+        assert!(matches!(
+            error,
+            MigrationError::Downgrade { from: 3, to: 1 }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "can not migrate from version 3 down to version 1"
+        );
+
+        let migrator = migrator().migration(Migration {
+            from: 1,
+            to: 3,
+            rules: vec![],
+        });
+        let error = migrator.migrate_json(&document(), 1, 2).err().unwrap();
+        assert!(matches!(
+            error,
+            MigrationError::Overshoot {
+                from: 1,
+                to: 3,
+                target: 2
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "migration from version 1 goes past version 2 to version 3"
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let mut document = json!({"name": "Robert Li"});
+        let rule = Rule::Split {
+            path: "name".to_string(),
+            separator: " ".to_string(),
+            into: vec![],
+        };
+        assert_eq!(
+            apply(&rule, &mut document),
+            Err("can not split name into no keys".to_string())
+        );
+        assert_eq!(document, json!({"name": "Robert Li"}));
+    }
+
+    #[test]
+    fn test_map() {
+        let mut document = json!({"levels": [1, "1", 2]});
+        let rule = Rule::Map {
+            path: "levels/*".to_string(),
+            values: vec![
+                Mapping {
+                    from: json!(1),
+                    to: json!("basic"),
+                },
+                Mapping {
+                    from: json!("1"),
+                    to: json!("premium"),
+                },
+            ],
+        };
+        apply(&rule, &mut document).unwrap();
+        assert_eq!(document, json!({"levels": ["basic", "premium", 2]}));
+    }
+}
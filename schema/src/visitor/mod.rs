@@ -1 +1,14 @@
+pub mod coerce;
+pub mod compatibility;
+pub mod default;
+pub mod diff;
+pub mod form;
+pub mod generate;
+pub mod json;
+pub mod lint;
+pub mod migrate;
+pub mod mutate;
+pub mod normalize;
+pub mod stream;
 pub mod validator;
+pub mod walk;
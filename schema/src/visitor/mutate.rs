@@ -0,0 +1,533 @@
+use crate::core::field::array::ArrayField;
+use crate::core::field::float::FloatField;
+use crate::core::field::integer::IntegerField;
+use crate::core::field::object::ObjectField;
+use crate::core::field::string::StringField;
+use crate::core::field::uinteger::UIntegerField;
+use crate::core::field::FieldEnum;
+use crate::core::value::FieldValue;
+use crate::visitor::generate::{generate_at, GeneratorOptions};
+use crate::visitor::validator::{Validator, ValidatorOptions};
+use crate::visitor::walk::{
+    walk_array_value, walk_object_value, walk_value, walk_with_value, Path, ValueVisitor,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A value which violates exactly one constraint of the schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mutation {
+    pub path: String,
+    /// The name of the constraint, e.g. `MaxLength`.
+    pub constraint: &'static str,
+    pub value: FieldValue,
+}
+
+struct Group {
+    path: String,
+    keys: Vec<String>,
+    constraint: &'static str,
+    candidates: Vec<FieldValue>,
+}
+
+fn keys(path: &Path) -> Vec<String> {
+    let mut keys = path.parent().map(keys).unwrap_or_default();
+    keys.extend(path.key().map(str::to_string));
+    keys
+}
+
+fn replace(whole: &FieldValue, keys: &[String], candidate: FieldValue) -> FieldValue {
+    let mut whole = whole.clone();
+    let mut value = &mut whole;
+    for key in keys {
+        value = match value {
+            FieldValue::Object(o) => o.get_mut(key),
+            FieldValue::Array(a) => key.parse().ok().and_then(|index: usize| a.get_mut(index)),
+            _ => None,
+        }
+        .expect("the keys are of a walked value");
+    }
+    *value = candidate;
+    whole
+}
+
+/// Truncates or pads the string to the length in graphemes.
+fn resize(s: &str, len: usize, pad: &str) -> String {
+    let mut graphemes: Vec<&str> = s.graphemes(true).take(len).collect();
+    while graphemes.len() < len {
+        graphemes.push(pad);
+    }
+    graphemes.concat()
+}
+
+fn next_float(v: f64, up: bool) -> Option<f64> {
+    let next = if v.is_nan() {
+        return None;
+    } else if v == 0.0 {
+        f64::from_bits(1).copysign(if up { 1.0 } else { -1.0 })
+    } else if (v > 0.0) == up {
+        f64::from_bits(v.to_bits() + 1)
+    } else {
+        f64::from_bits(v.to_bits() - 1)
+    };
+    next.is_finite().then_some(next)
+}
+
+struct Bounds<'a, T> {
+    enumeration: Option<&'a Vec<T>>,
+    minimum: Option<T>,
+    exclusive_minimum: Option<T>,
+    maximum: Option<T>,
+    exclusive_maximum: Option<T>,
+}
+
+impl<T: Copy + PartialEq> Bounds<'_, T> {
+    fn candidates(
+        &self,
+        up: impl Fn(T) -> Option<T>,
+        down: impl Fn(T) -> Option<T>,
+        value: impl Fn(T) -> FieldValue,
+    ) -> Vec<(&'static str, Vec<FieldValue>)> {
+        let bounds = [
+            ("Maximum", self.maximum.and_then(&up)),
+            ("ExclusiveMaximum", self.exclusive_maximum),
+            ("Minimum", self.minimum.and_then(&down)),
+            ("ExclusiveMinimum", self.exclusive_minimum),
+        ];
+        let mut candidates: Vec<_> = bounds
+            .into_iter()
+            .filter_map(|(constraint, candidate)| Some((constraint, vec![value(candidate?)])))
+            .collect();
+        if let Some(values) = self.enumeration {
+            let outside = values
+                .iter()
+                .flat_map(|v| [up(*v), down(*v)])
+                .flatten()
+                .filter(|v| !values.contains(v))
+                .map(&value)
+                .collect();
+            candidates.push(("Enum", outside));
+        }
+        candidates
+    }
+}
+
+/// Fills the missing children of the valid value with generated values.
+struct Completer<'a> {
+    rng: StdRng,
+    options: &'a GeneratorOptions,
+}
+
+impl ValueVisitor for Completer<'_> {
+    fn visit_value(&mut self, field: &FieldEnum, value: &mut FieldValue, path: &Path) {
+        if let FieldValue::Null = value {
+            if let Ok(generated) = generate_at(path, self.options, &mut self.rng) {
+                *value = generated;
+            }
+        }
+        walk_value(self, field, value, path)
+    }
+
+    fn visit_array(&mut self, field: &ArrayField, value: &mut FieldValue, path: &Path) {
+        if let FieldValue::Array(values) = value {
+            if values.is_empty() && field.item.is_some() && !self.options.is_deep(path.depth()) {
+                values.push(FieldValue::Null);
+            }
+        }
+        walk_array_value(self, field, value, path)
+    }
+
+    fn visit_object(&mut self, field: &ObjectField, value: &mut FieldValue, path: &Path) {
+        if let FieldValue::Object(values) = value {
+            if !self.options.is_deep(path.depth()) {
+                for key in field.properties.keys() {
+                    values.entry(key.clone()).or_insert(FieldValue::Null);
+                }
+                if let Some(additional) = &field.additional_properties {
+                    if values.keys().all(|key| field.properties.contains_key(key)) {
+                        values.insert(format!("{}_", additional.name()), FieldValue::Null);
+                    }
+                }
+            }
+        }
+        walk_object_value(self, field, value, path)
+    }
+}
+
+#[derive(Default)]
+struct Mutations {
+    groups: Vec<Group>,
+}
+
+impl Mutations {
+    fn push(&mut self, path: &Path, constraint: &'static str, candidates: Vec<FieldValue>) {
+        self.groups.push(Group {
+            path: path.to_string(),
+            keys: keys(path),
+            constraint,
+            candidates,
+        })
+    }
+
+    fn extend(&mut self, path: &Path, groups: Vec<(&'static str, Vec<FieldValue>)>) {
+        for (constraint, candidates) in groups {
+            self.push(path, constraint, candidates);
+        }
+    }
+}
+
+impl ValueVisitor for Mutations {
+    fn visit_value(&mut self, field: &FieldEnum, value: &mut FieldValue, path: &Path) {
+        let wrong_type = match field {
+            FieldEnum::Reference(_) => return,
+            FieldEnum::String(_) => FieldValue::Integer(0),
+            _ => FieldValue::String(String::new()),
+        };
+        self.push(path, "Type", vec![wrong_type]);
+        walk_value(self, field, value, path)
+    }
+
+    fn visit_array(&mut self, field: &ArrayField, value: &mut FieldValue, path: &Path) {
+        let FieldValue::Array(values) = value else {
+            return;
+        };
+        if let (Some(first), Some(true)) = (values.first(), field.unique) {
+            let duplicated = vec![first.clone(), first.clone()];
+            self.push(path, "Unique", vec![FieldValue::Array(duplicated)]);
+        }
+        // only the first item is mutated
+        let mut first = FieldValue::Array(values.iter().take(1).cloned().collect());
+        walk_array_value(self, field, &mut first, path)
+    }
+
+    fn visit_object(&mut self, field: &ObjectField, value: &mut FieldValue, path: &Path) {
+        let FieldValue::Object(values) = value else {
+            return;
+        };
+        for key in field.required.iter().flatten() {
+            if values.contains_key(key) {
+                let mut missing = values.clone();
+                missing.remove(key);
+                self.push(path, "Required", vec![FieldValue::Object(missing)]);
+            }
+        }
+        walk_object_value(self, field, value, path)
+    }
+
+    fn visit_float(&mut self, field: &FloatField, _value: &mut FieldValue, path: &Path) {
+        let bounds = Bounds {
+            enumeration: field.enumeration.as_ref(),
+            minimum: field.minimum,
+            exclusive_minimum: field.exclusive_minimum,
+            maximum: field.maximum,
+            exclusive_maximum: field.exclusive_maximum,
+        };
+        let candidates = bounds.candidates(
+            |v| next_float(v, true),
+            |v| next_float(v, false),
+            FieldValue::Float,
+        );
+        self.extend(path, candidates)
+    }
+
+    fn visit_integer(&mut self, field: &IntegerField, _value: &mut FieldValue, path: &Path) {
+        let bounds = Bounds {
+            enumeration: field.enumeration.as_ref(),
+            minimum: field.minimum,
+            exclusive_minimum: field.exclusive_minimum,
+            maximum: field.maximum,
+            exclusive_maximum: field.exclusive_maximum,
+        };
+        let candidates = bounds.candidates(
+            |v| v.checked_add(1),
+            |v| v.checked_sub(1),
+            FieldValue::Integer,
+        );
+        self.extend(path, candidates)
+    }
+
+    fn visit_uinteger(&mut self, field: &UIntegerField, _value: &mut FieldValue, path: &Path) {
+        let bounds = Bounds {
+            enumeration: field.enumeration.as_ref(),
+            minimum: field.minimum,
+            exclusive_minimum: field.exclusive_minimum,
+            maximum: field.maximum,
+            exclusive_maximum: field.exclusive_maximum,
+        };
+        let candidates = bounds.candidates(
+            |v| v.checked_add(1),
+            |v| v.checked_sub(1),
+            FieldValue::UInteger,
+        );
+        self.extend(path, candidates)
+    }
+
+    fn visit_string(&mut self, field: &StringField, value: &mut FieldValue, path: &Path) {
+        let valid = match value {
+            FieldValue::String(s) => s.as_str(),
+            _ => "",
+        };
+        let last = valid.graphemes(true).next_back().unwrap_or("a");
+        if let Some(min) = field.min_length.filter(|min| *min > 0) {
+            let candidates = [resize(valid, min - 1, last), "a".repeat(min - 1)];
+            self.push(
+                path,
+                "MinLength",
+                candidates.map(FieldValue::String).to_vec(),
+            );
+        }
+        if let Some(max) = field.max_length {
+            let candidates = [resize(valid, max + 1, last), "a".repeat(max + 1)];
+            self.push(
+                path,
+                "MaxLength",
+                candidates.map(FieldValue::String).to_vec(),
+            );
+        }
+        let others = [
+            String::new(),
+            format!("{}!", valid),
+            format!("!{}", valid),
+            valid.chars().rev().collect(),
+            "a".to_string(),
+            "0".to_string(),
+            " ".to_string(),
+        ];
+        if field.pattern.is_some() {
+            self.push(
+                path,
+                "Pattern",
+                others.clone().map(FieldValue::String).to_vec(),
+            );
+        }
+        if let Some(values) = &field.enumeration {
+            let candidates = values
+                .iter()
+                .map(|v| format!("{}_", v))
+                .chain(others)
+                .filter(|v| !values.contains(v))
+                .map(FieldValue::String)
+                .collect();
+            self.push(path, "Enum", candidates);
+        }
+    }
+}
+
+/// Generates values which violate exactly one constraint of the schema each, for negative tests.
+#[derive(Debug)]
+pub struct Mutator {
+    validator: Validator,
+    options: GeneratorOptions,
+}
+
+impl Mutator {
+    pub fn new(field: impl Into<FieldEnum>) -> Self {
+        Mutator::with_options(field, GeneratorOptions::default())
+    }
+
+    pub fn with_options(field: impl Into<FieldEnum>, options: GeneratorOptions) -> Self {
+        Mutator {
+            validator: Validator::with_options(field, ValidatorOptions::new().indexed_paths(true)),
+            options,
+        }
+    }
+
+    pub fn schema(&self) -> &FieldEnum {
+        self.validator.schema()
+    }
+
+    pub fn mutations(&self, seed: u64) -> Vec<Mutation> {
+        self.mutate(&FieldValue::Null, seed)
+    }
+
+    /// Mutates the valid value, every constraint of every field in turn. A constraint is skipped
+    /// if it can not be violated alone, e.g. a `maximum` beyond the `enum`.
+    pub fn mutate(&self, valid: &FieldValue, seed: u64) -> Vec<Mutation> {
+        let schema = self.schema();
+        let mut valid = valid.clone();
+        let mut completer = Completer {
+            rng: StdRng::seed_from_u64(seed),
+            options: &self.options,
+        };
+        walk_with_value(&mut completer, schema, &mut valid);
+        let mut mutations = Mutations::default();
+        walk_with_value(&mut mutations, schema, &mut valid);
+
+        mutations
+            .groups
+            .into_iter()
+            .filter_map(|group| {
+                let value = group
+                    .candidates
+                    .into_iter()
+                    .map(|candidate| replace(&valid, &group.keys, candidate))
+                    .find(|candidate| match self.validator.validate(candidate) {
+                        Ok(_) => false,
+                        Err(errors) => {
+                            errors.len() == 1
+                                && errors.get(&group.path).is_some_and(|errors| {
+                                    errors.iter().all(|e| e.constraint() == group.constraint)
+                                })
+                        }
+                    })?;
+                Some(Mutation {
+                    path: group.path,
+                    constraint: group.constraint,
+                    value,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::FieldEnum;
+    use crate::core::value::FieldValue;
+    use crate::visitor::json::from_json;
+    use crate::visitor::mutate::{next_float, Mutator};
+    use crate::visitor::validator::{Validator, ValidatorOptions};
+    use serde_json::json;
+
+    fn client_schema() -> FieldEnum {
+        serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "client",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "name": "name",
+                        "minLength": 2,
+                        "maxLength": 8,
+                        "pattern": "^[a-z]+$"
+                    },
+                    "level": {"type": "string", "name": "level", "enum": ["basic", "premium"]},
+                    "age": {"type": "uinteger", "name": "age", "exclusiveMaximum": 200},
+                    "ratio": {"type": "float", "name": "ratio", "minimum": 0.0, "maximum": 1.0},
+                    "rank": {"type": "integer", "name": "rank", "enum": [1, 2, 3]},
+                    "tags": {
+                        "type": "array",
+                        "name": "tags",
+                        "item": {"type": "string", "name": "tag", "maxLength": 4},
+                        "unique": true
+                    }
+                },
+                "required": ["name", "level"]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_mutations() {
+        let mutator = Mutator::new(client_schema());
+        let mutations = mutator.mutations(7);
+        let validator =
+            Validator::with_options(client_schema(), ValidatorOptions::new().indexed_paths(true));
+        for mutation in &mutations {
+            let errors = validator.validate(&mutation.value).err().unwrap();
+            assert_eq!(errors.len(), 1);
+            assert!(errors[&mutation.path]
+                .iter()
+                .all(|e| e.constraint() == mutation.constraint));
+        }
+        let found = mutations
+            .iter()
+            .map(|m| (m.path.as_str(), m.constraint))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                ("client", "Type"),
+                ("client", "Required"),
+                ("client", "Required"),
+                ("client/age", "Type"),
+                ("client/age", "ExclusiveMaximum"),
+                ("client/level", "Type"),
+                ("client/level", "Enum"),
+                ("client/name", "Type"),
+                ("client/name", "MinLength"),
+                ("client/name", "MaxLength"),
+                ("client/name", "Pattern"),
+                ("client/rank", "Type"),
+                ("client/rank", "Enum"),
+                ("client/ratio", "Type"),
+                ("client/ratio", "Maximum"),
+                ("client/ratio", "Minimum"),
+                ("client/tags", "Type"),
+                ("client/tags", "Unique"),
+                ("client/tags/0", "Type"),
+                ("client/tags/0", "MaxLength"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mutate() {
+        let schema = client_schema();
+        let valid = from_json(
+            &schema,
+            &json!({"name": "robert", "level": "basic", "age": 32}),
+        );
+        let mutations = Mutator::new(schema).mutate(&valid, 0);
+
+        let max_length = mutations
+            .iter()
+            .find(|m| m.path == "client/name" && m.constraint == "MaxLength")
+            .unwrap();
+        let FieldValue::Object(o) = &max_length.value else {
+            panic!("object is expected")
+        };
+        assert_eq!(o["name"], FieldValue::String("robertttt".to_string()));
+        assert_eq!(o["age"], FieldValue::UInteger(32));
+
+        let exclusive = mutations
+            .iter()
+            .find(|m| m.constraint == "ExclusiveMaximum")
+            .unwrap();
+        let FieldValue::Object(o) = &exclusive.value else {
+            panic!("object is expected")
+        };
+        assert_eq!(o["age"], FieldValue::UInteger(200));
+        assert_eq!(o["name"], FieldValue::String("robert".to_string()));
+    }
+
+    #[test]
+    fn test_recursive() {
+        let schema: FieldEnum = serde_json::from_value(json!({
+            "type": "object",
+            "name": "category",
+            "properties": {
+                "name": {"type": "string", "name": "name", "maxLength": 8},
+                "parent": {"type": "reference", "name": "parent", "reference": "category"}
+            }
+        }))
+        .unwrap();
+        let valid = from_json(
+            &schema,
+            &json!({"name": "novels", "parent": {"name": "books"}}),
+        );
+        let paths = Mutator::new(schema)
+            .mutate(&valid, 0)
+            .into_iter()
+            .filter(|m| m.constraint == "MaxLength")
+            .map(|m| m.path)
+            .collect::<Vec<_>>();
+        assert!(paths.contains(&"category/name".to_string()));
+        assert!(paths.contains(&"category/parent/name".to_string()));
+    }
+
+    #[test]
+    fn test_next_float() {
+        assert_eq!(next_float(1.0, true), Some(1.0000000000000002));
+        assert_eq!(next_float(1.0, false), Some(0.9999999999999999));
+        assert_eq!(next_float(-1.0, true), Some(-0.9999999999999999));
+        assert_eq!(next_float(0.0, true), Some(f64::from_bits(1)));
+        assert_eq!(next_float(-0.0, false), Some(-f64::from_bits(1)));
+        assert_eq!(next_float(f64::MAX, true), None);
+        assert_eq!(next_float(f64::MIN, false), None);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::core::field::string::StringField;
+use crate::core::field::FieldEnum;
+use crate::core::transform::apply;
+use crate::core::value::FieldValue;
+use crate::visitor::walk::{walk_with_value, Path, ValueVisitor};
+
+struct Normalizer;
+
+impl ValueVisitor for Normalizer {
+    fn visit_string(&mut self, field: &StringField, value: &mut FieldValue, _path: &Path) {
+        if let (Some(transforms), FieldValue::String(s)) = (&field.transforms, value) {
+            *s = apply(transforms, s);
+        }
+    }
+}
+
+/// Returns a copy of the value with the transforms of the string fields applied.
+pub fn normalize(schema: &FieldEnum, value: &FieldValue) -> FieldValue {
+    let mut value = value.clone();
+    walk_with_value(&mut Normalizer, schema, &mut value);
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::array::ArrayFieldBuilder;
+    use crate::core::field::object::ObjectFieldBuilder;
+    use crate::core::field::string::StringFieldBuilder;
+    use crate::core::field::FieldEnum;
+    use crate::core::transform::Transform;
+    use crate::core::value::FieldValue;
+    use crate::visitor::normalize::{normalize, Normalizer};
+    use crate::visitor::validator::Validator;
+    use crate::visitor::walk::ValueVisitor;
+    use std::collections::BTreeMap;
+
+    fn client_schema() -> FieldEnum {
+        ObjectFieldBuilder::new()
+            .name("client")
+            .property(
+                "name",
+                StringFieldBuilder::new()
+                    .name("name")
+                    .transforms(vec![Transform::Trim, Transform::CollapseWhitespace])
+                    .min_length(1)
+                    .max_length(9)
+                    .build(),
+            )
+            .property(
+                "tags",
+                ArrayFieldBuilder::new()
+                    .name("tags")
+                    .item(
+                        StringFieldBuilder::new()
+                            .name("tag")
+                            .transforms(vec![Transform::Lowercase])
+                            .enumeration(vec!["vip".to_string()])
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build()
+            .into()
+    }
+
+    fn client(name: &str, tags: &[&str]) -> FieldValue {
+        FieldValue::Object(BTreeMap::from([
+            ("name".to_string(), FieldValue::String(name.to_string())),
+            (
+                "tags".to_string(),
+                FieldValue::Array(
+                    tags.iter()
+                        .map(|t| FieldValue::String(t.to_string()))
+                        .collect(),
+                ),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn test_normalize() {
+        let schema = client_schema();
+        assert_eq!(
+            normalize(&schema, &client("  Robert \t Li ", &["VIP"])),
+            client("Robert Li", &["vip"])
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let validator = Validator::new(client_schema());
+        assert!(validator
+            .validate(&client(" Robert  Li ", &["Vip"]))
+            .is_ok());
+        assert!(validator.is_valid(&client(" Robert  Li ", &["Vip"])));
+
+        let errors = validator.validate(&client("   ", &[])).err().unwrap();
+        assert!(errors.contains_key("client/name"));
+        assert!(!validator.is_valid(&client("   ", &[])));
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let schema: FieldEnum = serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "node",
+                "properties": {
+                    "name": {"type": "string", "name": "name", "transforms": ["lowercase"]},
+                    "child": {"type": "reference", "name": "child", "reference": "node"}
+                }
+            }"#,
+        )
+        .unwrap();
+        let node = |child: Option<FieldValue>| {
+            let mut o = BTreeMap::from([("name".to_string(), FieldValue::String("A".to_string()))]);
+            if let Some(child) = child {
+                o.insert("child".to_string(), child);
+            }
+            FieldValue::Object(o)
+        };
+        let mut value = node(None);
+        for _ in 1..100 {
+            value = node(Some(value));
+        }
+
+        let mut value = &normalize(&schema, &value);
+        let mut names = vec![];
+        while let FieldValue::Object(o) = value {
+            names.push(o["name"].to_string());
+            match o.get("child") {
+                Some(child) => value = child,
+                None => break,
+            }
+        }
+        assert_eq!(
+            names.iter().filter(|n| *n == "a").count(),
+            Normalizer.max_depth() - 1
+        );
+        assert_eq!(
+            names.iter().filter(|n| *n == "A").count(),
+            100 - Normalizer.max_depth() + 1
+        );
+    }
+}
@@ -0,0 +1,432 @@
+use crate::visitor::json::from_json;
+use crate::visitor::validator::{ValidationError, Validator};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Record {
+    pub index: usize,
+    /// The 1-based line number where the record starts.
+    pub line: usize,
+    pub result: Result<(), RecordError>,
+}
+
+#[derive(Debug, Error)]
+pub enum RecordError {
+    #[error("record is not valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("record does not match the schema")]
+    Invalid(BTreeMap<String, Vec<ValidationError>>),
+}
+
+#[derive(Debug, Error)]
+pub enum StreamError {
+    #[error("failed to read the stream: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid JSON array at line {line}: {message}")]
+    Syntax { line: usize, message: String },
+}
+
+fn validate_record(validator: &Validator, index: usize, line: usize, bytes: &[u8]) -> Record {
+    let result = match serde_json::from_slice::<Value>(bytes) {
+        Ok(value) => validator
+            .validate(&from_json(validator.schema(), &value))
+            .map_err(RecordError::Invalid),
+        Err(e) => Err(RecordError::Json(e)),
+    };
+    Record {
+        index,
+        line,
+        result,
+    }
+}
+
+/// Validates newline-delimited JSON record by record, blank lines are skipped.
+pub struct NdjsonRecords<'v, R: BufRead> {
+    validator: &'v Validator,
+    reader: R,
+    buffer: Vec<u8>,
+    index: usize,
+    line: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Iterator for NdjsonRecords<'_, R> {
+    type Item = Result<Record, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.buffer.clear();
+            match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    self.line += 1;
+                    if self.buffer.iter().all(|b| b.is_ascii_whitespace()) {
+                        continue;
+                    }
+                    let record =
+                        validate_record(self.validator, self.index, self.line, &self.buffer);
+                    self.index += 1;
+                    return Some(Ok(record));
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+        None
+    }
+}
+
+pub fn ndjson<R: BufRead>(validator: &Validator, reader: R) -> NdjsonRecords<'_, R> {
+    NdjsonRecords {
+        validator,
+        reader,
+        buffer: vec![],
+        index: 0,
+        line: 0,
+        done: false,
+    }
+}
+
+#[derive(PartialEq)]
+enum ArrayState {
+    Start,
+    Items,
+    Trailer,
+    End,
+}
+
+/// Validates the items of a top-level JSON array record by record.
+pub struct JsonArrayRecords<'v, R: BufRead> {
+    validator: &'v Validator,
+    reader: R,
+    buffer: Vec<u8>,
+    state: ArrayState,
+    index: usize,
+    line: usize,
+}
+
+impl<R: BufRead> JsonArrayRecords<'_, R> {
+    fn next_non_whitespace(&mut self) -> Result<Option<u8>, StreamError> {
+        loop {
+            let chunk = self.reader.fill_buf()?;
+            if chunk.is_empty() {
+                return Ok(None);
+            }
+            let end = chunk.iter().position(|b| !b.is_ascii_whitespace());
+            let skipped = &chunk[..end.unwrap_or(chunk.len())];
+            self.line += skipped.iter().filter(|b| **b == b'\n').count();
+            match end {
+                Some(i) => {
+                    let b = chunk[i];
+                    self.reader.consume(i + 1);
+                    return Ok(Some(b));
+                }
+                None => {
+                    let len = chunk.len();
+                    self.reader.consume(len);
+                }
+            }
+        }
+    }
+
+    fn syntax_error(&mut self, message: &str) -> Option<Result<Record, StreamError>> {
+        self.state = ArrayState::End;
+        Some(Err(StreamError::Syntax {
+            line: self.line,
+            message: message.to_string(),
+        }))
+    }
+
+    fn read_item(&mut self) -> Result<Option<(usize, bool)>, StreamError> {
+        self.buffer.clear();
+        let mut line = self.line;
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        loop {
+            let chunk = self.reader.fill_buf()?;
+            if chunk.is_empty() {
+                return Ok(None);
+            }
+            let mut start = 0;
+            for (i, &b) in chunk.iter().enumerate() {
+                if b == b'\n' {
+                    self.line += 1;
+                }
+                if in_string {
+                    match b {
+                        _ if escaped => escaped = false,
+                        b'\\' => escaped = true,
+                        b'"' => in_string = false,
+                        _ => {}
+                    }
+                    continue;
+                }
+                match b {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' if depth > 0 => depth -= 1,
+                    b',' | b']' if depth == 0 => {
+                        self.buffer.extend_from_slice(&chunk[start..i]);
+                        self.reader.consume(i + 1);
+                        return Ok(Some((line, b == b']')));
+                    }
+                    _ if b.is_ascii_whitespace() && self.buffer.is_empty() && start == i => {
+                        start = i + 1;
+                        line = self.line;
+                    }
+                    _ => {}
+                }
+            }
+            self.buffer.extend_from_slice(&chunk[start..]);
+            let len = chunk.len();
+            self.reader.consume(len);
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for JsonArrayRecords<'_, R> {
+    type Item = Result<Record, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == ArrayState::Start {
+            match self.next_non_whitespace() {
+                Ok(Some(b'[')) => self.state = ArrayState::Items,
+                Ok(_) => return self.syntax_error("a JSON array is expected"),
+                Err(e) => {
+                    self.state = ArrayState::End;
+                    return Some(Err(e));
+                }
+            }
+        }
+        if self.state == ArrayState::Trailer {
+            self.state = ArrayState::End;
+            return match self.next_non_whitespace() {
+                Ok(Some(_)) => self.syntax_error("unexpected data after the JSON array"),
+                Ok(None) => None,
+                Err(e) => Some(Err(e)),
+            };
+        }
+        if self.state == ArrayState::End {
+            return None;
+        }
+
+        match self.read_item() {
+            Ok(Some((line, last))) => {
+                if self.buffer.is_empty() {
+                    match (last, self.index) {
+                        (true, 0) => {
+                            self.state = ArrayState::Trailer;
+                            return self.next();
+                        }
+                        (true, _) => {
+                            return self.syntax_error("a trailing comma in the JSON array")
+                        }
+                        (false, _) => {
+                            return self.syntax_error("an item of the JSON array is empty")
+                        }
+                    }
+                }
+                if last {
+                    self.state = ArrayState::Trailer;
+                }
+                let record = validate_record(self.validator, self.index, line, &self.buffer);
+                self.index += 1;
+                Some(Ok(record))
+            }
+            Ok(None) => self.syntax_error("the JSON array is not closed"),
+            Err(e) => {
+                self.state = ArrayState::End;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+pub fn json_array<R: BufRead>(validator: &Validator, reader: R) -> JsonArrayRecords<'_, R> {
+    JsonArrayRecords {
+        validator,
+        reader,
+        buffer: vec![],
+        state: ArrayState::Start,
+        index: 0,
+        line: 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::object::ObjectFieldBuilder;
+    use crate::core::field::string::StringFieldBuilder;
+    use crate::core::field::uinteger::UIntegerFieldBuilder;
+    use crate::visitor::stream::{json_array, ndjson, RecordError, StreamError};
+    use crate::visitor::validator::Validator;
+    use std::io::{BufReader, Cursor};
+
+    fn validator() -> Validator {
+        Validator::new(
+            ObjectFieldBuilder::new()
+                .name("client")
+                .property(
+                    "name",
+                    StringFieldBuilder::new().name("name").max_length(8).build(),
+                )
+                .property(
+                    "age",
+                    UIntegerFieldBuilder::new().name("age").maximum(200).build(),
+                )
+                .required(vec!["name".to_string()])
+                .build(),
+        )
+    }
+
+    #[test]
+    fn test_ndjson() {
+        let validator = validator();
+        let input = r#"{"name": "Robert", "age": 32}
+
+{"name": "Robert", "age": 201}
+{"name": "Robert",
+{"age": 32}
+"#;
+        let records: Vec<_> = ndjson(&validator, Cursor::new(input))
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!(
+            records
+                .iter()
+                .map(|r| (r.index, r.line))
+                .collect::<Vec<_>>(),
+            vec![(0, 1), (1, 3), (2, 4), (3, 5)]
+        );
+        assert!(records[0].result.is_ok());
+        assert!(matches!(
+            &records[1].result,
+            Err(RecordError::Invalid(e)) if e.contains_key("client/age")
+        ));
+        assert!(matches!(records[2].result, Err(RecordError::Json(_))));
+        assert!(matches!(
+            &records[3].result,
+            Err(RecordError::Invalid(e)) if e.contains_key("client")
+        ));
+    }
+
+    #[test]
+    fn test_json_array() {
+        let validator = validator();
+        let input = r#"[
+    {"name": "Robert", "age": 32},
+    {"name": "Robert [\"Bob\"]", "age": 32},
+    {
+        "name": "Linda",
+        "age": 201
+    }
+]"#;
+        let records: Vec<_> = json_array(&validator, BufReader::new(Cursor::new(input)))
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!(
+            records
+                .iter()
+                .map(|r| (r.index, r.line))
+                .collect::<Vec<_>>(),
+            vec![(0, 2), (1, 3), (2, 4)]
+        );
+        assert!(records[0].result.is_ok());
+        assert!(matches!(
+            &records[1].result,
+            Err(RecordError::Invalid(e)) if e.contains_key("client/name")
+        ));
+        assert!(matches!(
+            &records[2].result,
+            Err(RecordError::Invalid(e)) if e.contains_key("client/age")
+        ));
+
+        assert_eq!(json_array(&validator, Cursor::new(" [ ] ")).count(), 0);
+
+        // the items span several chunks of a small buffer
+        let chunked: Vec<_> =
+            json_array(&validator, BufReader::with_capacity(3, Cursor::new(input)))
+                .map(|r| r.unwrap())
+                .collect();
+        assert_eq!(
+            chunked
+                .iter()
+                .map(|r| (r.index, r.line, r.result.is_ok()))
+                .collect::<Vec<_>>(),
+            vec![(0, 2, true), (1, 3, false), (2, 4, false)]
+        );
+    }
+
+    #[test]
+    fn test_json_array_syntax() {
+        let validator = validator();
+        let mut records = json_array(&validator, Cursor::new(r#"{"name": "Robert"}"#));
+        assert!(matches!(
+            records.next(),
+            Some(Err(StreamError::Syntax { line: 1, .. }))
+        ));
+        assert!(records.next().is_none());
+
+        let mut records = json_array(&validator, Cursor::new(r#"[{"name": "Robert"}"#));
+        assert!(matches!(
+            records.next(),
+            Some(Err(StreamError::Syntax { .. }))
+        ));
+        assert!(records.next().is_none());
+    }
+
+    #[test]
+    fn test_json_array_trailing_data() {
+        let validator = validator();
+        let mut records = json_array(
+            &validator,
+            Cursor::new("[{\"name\": \"Robert\"}]\n{\"name\": \"Linda\"}"),
+        );
+        assert!(records.next().unwrap().unwrap().result.is_ok());
+        assert!(matches!(
+            records.next(),
+            Some(Err(StreamError::Syntax { line: 2, .. }))
+        ));
+        assert!(records.next().is_none());
+
+        let mut records = json_array(&validator, Cursor::new("[] x"));
+        assert!(matches!(
+            records.next(),
+            Some(Err(StreamError::Syntax { .. }))
+        ));
+        assert!(records.next().is_none());
+
+        assert_eq!(
+            json_array(&validator, Cursor::new("[{\"name\": \"Robert\"}] \n")).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_json_array_empty_item() {
+        let validator = validator();
+        for input in [
+            "[{\"name\": \"Robert\"},]",
+            "[{\"name\": \"Robert\"}, ,{\"name\": \"Linda\"}]",
+            "[,]",
+        ] {
+            let results: Vec<_> = json_array(&validator, Cursor::new(input)).collect();
+            assert!(
+                matches!(results.last(), Some(Err(StreamError::Syntax { .. }))),
+                "{}",
+                input
+            );
+            assert!(results[..results.len() - 1].iter().all(|r| r
+                .as_ref()
+                .unwrap()
+                .result
+                .is_ok()));
+        }
+    }
+}
@@ -1,17 +1,33 @@
-use crate::core::field::array::ArrayField;
-use crate::core::field::object::ObjectField;
+use crate::core::constraint::Constraint;
 use crate::core::field::Field;
 use crate::core::field::FieldEnum;
-use crate::core::value::{FieldValue, Reflect};
+use crate::core::transform::{apply, Transform};
+use crate::core::value::{FieldValueRef, Reflect};
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug)]
 pub struct ValidationError {
+    constraint: &'static str,
     message: String,
 }
 
+impl ValidationError {
+    pub(crate) fn new(constraint: &'static str, message: String) -> Self {
+        ValidationError {
+            constraint,
+            message,
+        }
+    }
+
+    /// The name of the violated constraint, e.g. `MaxLength`.
+    pub fn constraint(&self) -> &'static str {
+        self.constraint
+    }
+}
+
 impl Display for ValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.message)
@@ -20,119 +36,534 @@ impl Display for ValidationError {
 
 impl Error for ValidationError {}
 
-struct State {
-    value: FieldValue,
-    field_names: Vec<String>,
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+#[cfg(feature = "parallel")]
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 1024;
+
+#[derive(Debug, Clone)]
+pub struct ValidatorOptions {
+    max_depth: usize,
+    fail_fast: bool,
+    max_errors: Option<usize>,
+    indexed_paths: bool,
+    #[cfg(feature = "parallel")]
+    parallel_threshold: usize,
+}
+
+impl Default for ValidatorOptions {
+    fn default() -> Self {
+        ValidatorOptions {
+            max_depth: DEFAULT_MAX_DEPTH,
+            fail_fast: false,
+            max_errors: None,
+            indexed_paths: false,
+            #[cfg(feature = "parallel")]
+            parallel_threshold: DEFAULT_PARALLEL_THRESHOLD,
+        }
+    }
+}
+
+impl ValidatorOptions {
+    pub fn new() -> Self {
+        ValidatorOptions::default()
+    }
+
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    pub fn max_errors(mut self, max: usize) -> Self {
+        self.max_errors = Some(max);
+        self
+    }
+
+    /// Reports the array items by their index (e.g. `clients/3/age`) instead of the item name.
+    pub fn indexed_paths(mut self, indexed_paths: bool) -> Self {
+        self.indexed_paths = indexed_paths;
+        self
+    }
+
+    #[cfg(feature = "parallel")]
+    pub fn parallel_threshold(mut self, threshold: usize) -> Self {
+        self.parallel_threshold = threshold;
+        self
+    }
+
+    fn error_limit(&self) -> Option<usize> {
+        if self.fail_fast {
+            Some(1)
+        } else {
+            self.max_errors
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Slot {
+    name: String,
+    node: usize,
+}
+
+#[derive(Debug)]
+enum Node {
+    Field {
+        constraints: Vec<Box<dyn Constraint>>,
+        transforms: Vec<Transform>,
+        children: Children,
+    },
+    Unresolved {
+        reference: String,
+    },
+}
+
+#[derive(Debug)]
+enum Children {
+    None,
+    Item(Slot),
+    Properties {
+        properties: BTreeMap<String, Slot>,
+        additional: Option<usize>,
+    },
+}
+
+/// The schema compiled into a flat list of nodes, references are resolved to node indexes.
+#[derive(Debug)]
+struct Plan {
+    nodes: Vec<Node>,
+    root: Slot,
+}
+
+impl Plan {
+    fn compile(schema: &FieldEnum) -> Self {
+        let mut plan = Plan {
+            nodes: vec![],
+            root: Slot {
+                name: schema.name(),
+                node: 0,
+            },
+        };
+        plan.root.node = plan.compile_field(schema, &mut vec![]);
+        plan
+    }
+
+    fn compile_field<'a>(
+        &mut self,
+        field: &'a FieldEnum,
+        ancestors: &mut Vec<(&'a str, usize)>,
+    ) -> usize {
+        let (constraints, children) = match field {
+            FieldEnum::Array(f) => (f.constrains(), Some(field)),
+            FieldEnum::Boolean(f) => (f.constrains(), None),
+            FieldEnum::Float(f) => (f.constrains(), None),
+            FieldEnum::Integer(f) => (f.constrains(), None),
+            FieldEnum::UInteger(f) => (f.constrains(), None),
+            FieldEnum::Object(f) => (f.constrains(), Some(field)),
+            FieldEnum::String(f) => (f.constrains(), None),
+            FieldEnum::Email(f) => (f.constrains(), None),
+            FieldEnum::Datetime(f) => (f.constrains(), None),
+            FieldEnum::Date(f) => (f.constrains(), None),
+            FieldEnum::Time(f) => (f.constrains(), None),
+            FieldEnum::Reference(f) => {
+                return match ancestors
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| *name == f.reference)
+                {
+                    Some((_, node)) => *node,
+                    None => {
+                        self.nodes.push(Node::Unresolved {
+                            reference: f.reference.clone(),
+                        });
+                        self.nodes.len() - 1
+                    }
+                };
+            }
+        };
+
+        let transforms = match field {
+            FieldEnum::String(f) => f.transforms.clone().unwrap_or_default(),
+            _ => vec![],
+        };
+        let index = self.nodes.len();
+        self.nodes.push(Node::Field {
+            constraints,
+            transforms,
+            children: Children::None,
+        });
+
+        let children = match children {
+            Some(FieldEnum::Array(f)) => {
+                ancestors.push((f.name.as_str(), index));
+                let children = match &f.item {
+                    Some(item) => Children::Item(Slot {
+                        name: item.name(),
+                        node: self.compile_field(item, ancestors),
+                    }),
+                    None => Children::None,
+                };
+                ancestors.pop();
+                children
+            }
+            Some(FieldEnum::Object(f)) => {
+                ancestors.push((f.name.as_str(), index));
+                let mut properties = BTreeMap::new();
+                for (key, property) in &f.properties {
+                    properties.insert(
+                        key.clone(),
+                        Slot {
+                            name: property.name(),
+                            node: self.compile_field(property, ancestors),
+                        },
+                    );
+                }
+                let additional = f
+                    .additional_properties
+                    .as_ref()
+                    .map(|field| self.compile_field(field, ancestors));
+                ancestors.pop();
+                Children::Properties {
+                    properties,
+                    additional,
+                }
+            }
+            _ => Children::None,
+        };
+        if let Node::Field { children: c, .. } = &mut self.nodes[index] {
+            *c = children;
+        }
+        index
+    }
+}
+
+fn transform(transforms: &[Transform], value: &FieldValueRef) -> Option<FieldValueRef<'static>> {
+    match value {
+        FieldValueRef::String(s) if !transforms.is_empty() => {
+            Some(FieldValueRef::String(Cow::Owned(apply(transforms, s))))
+        }
+        _ => None,
+    }
+}
+
+struct State<'a> {
+    field_names: Vec<Cow<'a, str>>,
     errors: BTreeMap<String, Vec<ValidationError>>,
+    error_count: usize,
+    error_limit: Option<usize>,
 }
 
+impl State<'_> {
+    fn is_done(&self) -> bool {
+        matches!(self.error_limit, Some(limit) if self.error_count >= limit)
+    }
+
+    fn push_error(&mut self, field: String, error: ValidationError) {
+        if self.is_done() {
+            return;
+        }
+        self.errors.entry(field).or_default().push(error);
+        self.error_count += 1;
+    }
+}
+
+/// Validates values against a schema, which is compiled once when the validator is created.
 #[derive(Debug)]
 pub struct Validator {
     schema: FieldEnum,
+    plan: Plan,
+    options: ValidatorOptions,
 }
 
 impl Validator {
     fn report_error(&self, error: ValidationError, state: &mut State) {
-        let field = state.field_names.clone().join("/");
-        if state.errors.contains_key(field.as_str()) {
-            state.errors.get_mut(field.as_str()).unwrap().push(error);
-        } else {
-            state.errors.insert(field, vec![error]);
-        }
+        let field = state.field_names.join("/");
+        state.push_error(field, error);
     }
 
-    fn validate_field(&self, field: &(impl Field + ?Sized), state: &mut State) {
-        state.field_names.push(field.name().clone());
-        for constraint in field.constrains() {
-            match constraint.validate(&state.value) {
-                Ok(_) => {}
-                Err(e) => {
-                    self.report_error(
-                        ValidationError {
-                            message: e.to_string(),
+    fn visit<'a>(
+        &'a self,
+        node: usize,
+        name: Cow<'a, str>,
+        value: &FieldValueRef,
+        state: &mut State<'a>,
+    ) {
+        state.field_names.push(name);
+        if state.field_names.len() > self.options.max_depth {
+            self.report_error(
+                ValidationError::new(
+                    "MaxDepth",
+                    format!(
+                        "value is nested deeper than {} ({})",
+                        self.options.max_depth, "MaxDepth"
+                    ),
+                ),
+                state,
+            );
+            state.field_names.pop();
+            return;
+        }
+
+        match &self.plan.nodes[node] {
+            Node::Field {
+                constraints,
+                transforms,
+                children,
+            } => {
+                let transformed = transform(transforms, value);
+                let value = transformed.as_ref().unwrap_or(value);
+                for constraint in constraints {
+                    if state.is_done() {
+                        break;
+                    }
+                    if let Err(e) = constraint.validate_ref(value) {
+                        self.report_error(
+                            ValidationError::new(constraint.name(), e.to_string()),
+                            state,
+                        );
+                    }
+                }
+                match (children, value) {
+                    (Children::Item(item), FieldValueRef::Array(values)) => {
+                        self.visit_items(item, values, state);
+                    }
+                    (
+                        Children::Properties {
+                            properties,
+                            additional,
                         },
-                        state,
-                    );
+                        FieldValueRef::Object(o),
+                    ) => {
+                        for (key, value) in o {
+                            if state.is_done() {
+                                break;
+                            }
+                            // the additional properties are reported by their keys
+                            match (properties.get(key.as_ref()), additional) {
+                                (Some(property), _) => self.visit(
+                                    property.node,
+                                    Cow::Borrowed(property.name.as_str()),
+                                    value,
+                                    state,
+                                ),
+                                (None, Some(additional)) => self.visit(
+                                    *additional,
+                                    Cow::Owned(key.to_string()),
+                                    value,
+                                    state,
+                                ),
+                                (None, None) => {}
+                            }
+                        }
+                    }
+                    _ => {}
                 }
             }
+            Node::Unresolved { reference } => {
+                self.report_error(
+                    ValidationError::new(
+                        "Reference",
+                        format!(
+                            "reference {} can not be resolved ({})",
+                            reference, "Reference"
+                        ),
+                    ),
+                    state,
+                );
+            }
         }
         state.field_names.pop();
     }
 
-    fn visit_array(&self, array: &ArrayField, state: &mut State) {
-        self.validate_field(array, state);
-        state.field_names.push(array.name().clone());
-        if let FieldValue::Array(values) = state.value.clone() {
-            if let Some(item) = &array.item {
-                for value in values {
-                    state.value = value;
-                    self.visit(item, state);
-                }
+    fn item_name<'a>(&'a self, item: &'a Slot, index: usize) -> Cow<'a, str> {
+        if self.options.indexed_paths {
+            Cow::Owned(index.to_string())
+        } else {
+            Cow::Borrowed(item.name.as_str())
+        }
+    }
+
+    fn visit_items<'a>(&'a self, item: &'a Slot, values: &[FieldValueRef], state: &mut State<'a>) {
+        #[cfg(feature = "parallel")]
+        if values.len() >= self.options.parallel_threshold {
+            return self.par_visit_items(item, values, state);
+        }
+
+        for (index, value) in values.iter().enumerate() {
+            if state.is_done() {
+                break;
             }
+            self.visit(item.node, self.item_name(item, index), value, state);
         }
-        state.field_names.pop();
     }
 
-    fn visit_object(&self, object: &ObjectField, state: &mut State) {
-        self.validate_field(object, state);
-        state.field_names.push(object.name().clone());
-        if let FieldValue::Object(o) = state.value.clone() {
-            for (name, value) in o {
-                if let Some(field) = object.properties.get(name.as_str()) {
-                    state.value = value;
-                    self.visit(field, state);
+    #[cfg(feature = "parallel")]
+    fn par_visit_items<'a>(
+        &'a self,
+        item: &'a Slot,
+        values: &[FieldValueRef],
+        state: &mut State<'a>,
+    ) {
+        use rayon::prelude::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let field_names = &state.field_names;
+        let error_limit = state
+            .error_limit
+            .map(|limit| limit.saturating_sub(state.error_count));
+        let done_at = AtomicUsize::new(usize::MAX);
+        let item_states: Vec<State> = values
+            .par_iter()
+            .enumerate()
+            .filter(|(index, _)| *index < done_at.load(Ordering::Relaxed))
+            .map(|(index, value)| {
+                let mut item_state = State {
+                    field_names: field_names.clone(),
+                    errors: Default::default(),
+                    error_count: 0,
+                    error_limit,
                 };
+                self.visit(
+                    item.node,
+                    self.item_name(item, index),
+                    value,
+                    &mut item_state,
+                );
+                if item_state.is_done() {
+                    done_at.fetch_min(index, Ordering::Relaxed);
+                }
+                item_state
+            })
+            .collect();
+        for item_state in item_states {
+            for (field, errors) in item_state.errors {
+                for error in errors {
+                    state.push_error(field.clone(), error);
+                }
             }
         }
-        state.field_names.pop();
     }
 
-    fn visit(&self, field: &FieldEnum, state: &mut State) {
-        match field {
-            FieldEnum::Array(f) => self.visit_array(f, state),
-            FieldEnum::Boolean(f) => self.validate_field(f, state),
-            FieldEnum::Float(f) => self.validate_field(f, state),
-            FieldEnum::Integer(f) => self.validate_field(f, state),
-            FieldEnum::UInteger(f) => self.validate_field(f, state),
-            FieldEnum::Object(f) => self.visit_object(f, state),
-            FieldEnum::String(f) => self.validate_field(f, state),
-            FieldEnum::Email(f) => self.validate_field(f, state),
-            FieldEnum::Datetime(f) => self.validate_field(f, state),
-            FieldEnum::Date(f) => self.validate_field(f, state),
-            FieldEnum::Time(f) => self.validate_field(f, state),
+    fn check(&self, node: usize, value: &FieldValueRef, depth: usize) -> bool {
+        if depth > self.options.max_depth {
+            return false;
+        }
+
+        match &self.plan.nodes[node] {
+            Node::Field {
+                constraints,
+                transforms,
+                children,
+            } => {
+                let transformed = transform(transforms, value);
+                let value = transformed.as_ref().unwrap_or(value);
+                constraints.iter().all(|c| c.is_valid(value))
+                    && match (children, value) {
+                        (Children::Item(item), FieldValueRef::Array(values)) => values
+                            .iter()
+                            .all(|value| self.check(item.node, value, depth + 1)),
+                        (
+                            Children::Properties {
+                                properties,
+                                additional,
+                            },
+                            FieldValueRef::Object(o),
+                        ) => o.iter().all(|(key, value)| {
+                            match (properties.get(key.as_ref()), additional) {
+                                (Some(property), _) => self.check(property.node, value, depth + 1),
+                                (None, Some(additional)) => {
+                                    self.check(*additional, value, depth + 1)
+                                }
+                                (None, None) => true,
+                            }
+                        }),
+                        _ => true,
+                    }
+            }
+            Node::Unresolved { .. } => false,
         }
     }
 
-    pub fn new(field: impl Field) -> Self {
+    pub fn new(field: impl Into<FieldEnum>) -> Self {
+        Self::with_options(field, ValidatorOptions::default())
+    }
+
+    pub fn with_options(field: impl Into<FieldEnum>, options: ValidatorOptions) -> Self {
+        let schema: FieldEnum = field.into();
+        let plan = Plan::compile(&schema);
         Validator {
-            schema: field.into(),
+            schema,
+            plan,
+            options,
         }
     }
 
+    pub fn schema(&self) -> &FieldEnum {
+        &self.schema
+    }
+
     pub fn validate(
         &self,
         value: &impl Reflect,
     ) -> Result<(), BTreeMap<String, Vec<ValidationError>>> {
         let mut state = State {
-            value: value.field_value(),
             field_names: vec![],
             errors: Default::default(),
+            error_count: 0,
+            error_limit: self.options.error_limit(),
         };
 
-        self.visit(&self.schema, &mut state);
+        self.visit(
+            self.plan.root.node,
+            Cow::Borrowed(self.plan.root.name.as_str()),
+            &value.field_value_ref(),
+            &mut state,
+        );
         if state.errors.is_empty() {
             Ok(())
         } else {
             Err(state.errors)
         }
     }
+
+    pub fn is_valid(&self, value: &impl Reflect) -> bool {
+        self.check(self.plan.root.node, &value.field_value_ref(), 1)
+    }
+
+    pub fn validate_batch<T: Reflect + Sync>(
+        &self,
+        values: &[T],
+    ) -> Vec<Result<(), BTreeMap<String, Vec<ValidationError>>>> {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            values
+                .par_iter()
+                .map(|value| self.validate(value))
+                .collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            values.iter().map(|value| self.validate(value)).collect()
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::core::field::array::ArrayFieldBuilder;
     use crate::core::field::object::ObjectField;
+    use crate::core::field::reference::ReferenceFieldBuilder;
+    use crate::core::field::string::StringFieldBuilder;
     use crate::core::value::{FieldValue, Reflect};
-    use crate::visitor::validator::Validator;
+    #[cfg(feature = "parallel")]
+    use crate::visitor::validator::ValidationError;
+    use crate::visitor::validator::{Validator, ValidatorOptions};
     use std::collections::BTreeMap;
 
     #[test]
@@ -195,14 +626,174 @@ mod tests {
         };
         let result = validator.validate(&invalid_client);
         assert!(result.is_err());
+        let errors = result.err().unwrap();
+        let error = errors.get("client/age").unwrap().first().unwrap();
+        assert!(error
+            .message
+            .contains("value 201 is larger then 200 (Maximum)"));
+        assert_eq!(error.constraint(), "Maximum");
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let field = ArrayFieldBuilder::new()
+            .name("nested")
+            .item(
+                ReferenceFieldBuilder::new()
+                    .name("nested")
+                    .reference("nested")
+                    .build(),
+            )
+            .build();
+        let validator = Validator::with_options(field, ValidatorOptions::new().max_depth(3));
+
+        let value = vec![vec![Vec::<Vec<u64>>::new()]];
+        assert!(validator.validate(&value).is_ok());
+
+        let value = vec![vec![vec![Vec::<u64>::new()]]];
+        let result = validator.validate(&value);
         assert!(result
             .err()
             .unwrap()
-            .get("client/age")
+            .get("nested/nested/nested/nested")
             .unwrap()
-            .get(0)
+            .first()
             .unwrap()
             .message
-            .contains("value 201 is larger then 200 (Maximum)"));
+            .contains("value is nested deeper than 3 (MaxDepth)"));
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Validator>();
+    }
+
+    #[test]
+    fn test_fail_fast() {
+        let field = ArrayFieldBuilder::new()
+            .name("tags")
+            .item(StringFieldBuilder::new().name("tag").max_length(3).build())
+            .unique(true)
+            .build();
+
+        let value = vec!["meeting", "meeting", "email"];
+        let validator = Validator::with_options(field, ValidatorOptions::new().fail_fast(true));
+        let errors = validator.validate(&value).err().unwrap();
+        assert_eq!(errors.values().flatten().count(), 1);
+        assert!(errors.get("tags").unwrap()[0].message.contains("(Unique)"));
+    }
+
+    #[test]
+    fn test_max_errors() {
+        let field = ArrayFieldBuilder::new()
+            .name("tags")
+            .item(StringFieldBuilder::new().name("tag").max_length(3).build())
+            .build();
+        let value = vec!["meeting", "email", "kickoff", "call"];
+
+        let validator = Validator::new(
+            ArrayFieldBuilder::new()
+                .name("tags")
+                .item(StringFieldBuilder::new().name("tag").max_length(3).build())
+                .build(),
+        );
+        let errors = validator.validate(&value).err().unwrap();
+        assert_eq!(errors.values().flatten().count(), 4);
+
+        let validator = Validator::with_options(field, ValidatorOptions::new().max_errors(2));
+        let errors = validator.validate(&value).err().unwrap();
+        assert_eq!(errors.values().flatten().count(), 2);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let field = ArrayFieldBuilder::new()
+            .name("tags")
+            .item(StringFieldBuilder::new().name("tag").max_length(5).build())
+            .unique(true)
+            .build();
+        let validator = Validator::new(field);
+
+        assert!(validator.is_valid(&vec!["call", "email"]));
+        assert!(!validator.is_valid(&vec!["call", "call"]));
+        assert!(!validator.is_valid(&vec!["call", "meeting"]));
+        assert!(!validator.is_valid(&"call"));
+    }
+
+    #[test]
+    fn test_indexed_path() {
+        let field = || {
+            ArrayFieldBuilder::new()
+                .name("tags")
+                .item(StringFieldBuilder::new().name("tag").max_length(5).build())
+                .build()
+        };
+        let value = vec!["call", "meeting", "email", "kickoff"];
+
+        let validator = Validator::new(field());
+        let errors = validator.validate(&value).err().unwrap();
+        assert_eq!(errors.keys().collect::<Vec<&String>>(), vec!["tags/tag"]);
+        assert_eq!(errors["tags/tag"].len(), 2);
+
+        let validator =
+            Validator::with_options(field(), ValidatorOptions::new().indexed_paths(true));
+        let errors = validator.validate(&value).err().unwrap();
+        assert_eq!(
+            errors.keys().collect::<Vec<&String>>(),
+            vec!["tags/1", "tags/3"]
+        );
+    }
+
+    #[test]
+    fn test_validate_batch() {
+        let field = StringFieldBuilder::new().name("tag").max_length(5).build();
+        let validator = Validator::new(field);
+
+        let results = validator.validate_batch(&["call", "meeting", "email"]);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel() {
+        let field = || {
+            ArrayFieldBuilder::new()
+                .name("tags")
+                .item(StringFieldBuilder::new().name("tag").max_length(5).build())
+                .build()
+        };
+        let value: Vec<String> = (0..5000).map(|i| "a".repeat(i % 8)).collect();
+
+        let options = || ValidatorOptions::new().indexed_paths(true);
+        let sequential = Validator::with_options(field(), options().parallel_threshold(usize::MAX));
+        let parallel = Validator::with_options(field(), options().parallel_threshold(1));
+        let format = |errors: BTreeMap<String, Vec<ValidationError>>| {
+            errors
+                .into_iter()
+                .map(|(k, v)| (k, v.iter().map(|e| e.to_string()).collect()))
+                .collect::<Vec<(String, Vec<String>)>>()
+        };
+        let expected = format(sequential.validate(&value).err().unwrap());
+        assert_eq!(expected.len(), 5000 / 8 * 2);
+        assert_eq!(format(parallel.validate(&value).err().unwrap()), expected);
+
+        let sequential = Validator::with_options(
+            field(),
+            options().parallel_threshold(usize::MAX).max_errors(3),
+        );
+        let parallel =
+            Validator::with_options(field(), options().parallel_threshold(1).max_errors(3));
+        let expected = format(sequential.validate(&value).err().unwrap());
+        assert_eq!(
+            expected
+                .iter()
+                .map(|(k, _)| k.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["tags/14", "tags/6", "tags/7"]
+        );
+        assert_eq!(format(parallel.validate(&value).err().unwrap()), expected);
     }
 }
@@ -0,0 +1,556 @@
+use crate::core::field::array::ArrayField;
+use crate::core::field::boolean::BooleanField;
+use crate::core::field::date::DateField;
+use crate::core::field::datetime::DatetimeField;
+use crate::core::field::email::EmailField;
+use crate::core::field::float::FloatField;
+use crate::core::field::integer::IntegerField;
+use crate::core::field::object::ObjectField;
+use crate::core::field::reference::ReferenceField;
+use crate::core::field::string::StringField;
+use crate::core::field::time::TimeField;
+use crate::core::field::uinteger::UIntegerField;
+use crate::core::field::FieldEnum;
+use crate::core::value::FieldValue;
+use crate::visitor::validator::DEFAULT_MAX_DEPTH;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// The location of a visited field (or value) from the root, displayed joined by `/`.
+#[derive(Debug)]
+pub struct Path<'a> {
+    parent: Option<&'a Path<'a>>,
+    segment: String,
+    key: Option<String>,
+    field: &'a FieldEnum,
+}
+
+impl<'a> Path<'a> {
+    pub fn root(field: &'a FieldEnum) -> Self {
+        Path {
+            parent: None,
+            segment: field.name(),
+            key: None,
+            field,
+        }
+    }
+
+    pub fn child(&'a self, segment: String, key: Option<String>, field: &'a FieldEnum) -> Path<'a> {
+        Path {
+            parent: Some(self),
+            segment,
+            key,
+            field,
+        }
+    }
+
+    pub fn parent(&self) -> Option<&'a Path<'a>> {
+        self.parent
+    }
+
+    pub fn segment(&self) -> &str {
+        &self.segment
+    }
+
+    /// The key of the value in its parent value, if any.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    pub fn field(&self) -> &'a FieldEnum {
+        self.field
+    }
+
+    /// The root is at depth 1.
+    pub fn depth(&self) -> usize {
+        self.parent.map_or(1, |parent| parent.depth() + 1)
+    }
+
+    pub fn segments(&self) -> Vec<&str> {
+        let mut segments = match self.parent {
+            Some(parent) => parent.segments(),
+            None => vec![],
+        };
+        segments.push(&self.segment);
+        segments
+    }
+
+    /// Finds the closest enclosing array or object with the name, as a reference does.
+    pub fn find(&self, name: &str) -> Option<&'a FieldEnum> {
+        match self.field {
+            FieldEnum::Array(_) | FieldEnum::Object(_) if self.field.name() == name => {
+                Some(self.field)
+            }
+            _ => self.parent.and_then(|parent| parent.find(name)),
+        }
+    }
+
+    /// Resolves a reference to the field it refers to, other fields are returned as is.
+    pub fn resolve(&self, field: &'a FieldEnum) -> Option<&'a FieldEnum> {
+        match field {
+            FieldEnum::Reference(r) => self.find(&r.reference),
+            _ => Some(field),
+        }
+    }
+}
+
+impl Display for Path<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(parent) = self.parent {
+            write!(f, "{}/", parent)?;
+        }
+        write!(f, "{}", self.segment)
+    }
+}
+
+/// A visitor over the fields of a schema, the references are not resolved.
+pub trait SchemaVisitor {
+    fn visit_field(&mut self, field: &FieldEnum, path: &Path) {
+        walk_field(self, field, path)
+    }
+
+    fn visit_array(&mut self, field: &ArrayField, path: &Path) {
+        walk_array(self, field, path)
+    }
+
+    fn visit_object(&mut self, field: &ObjectField, path: &Path) {
+        walk_object(self, field, path)
+    }
+
+    fn visit_boolean(&mut self, _field: &BooleanField, _path: &Path) {}
+
+    fn visit_float(&mut self, _field: &FloatField, _path: &Path) {}
+
+    fn visit_integer(&mut self, _field: &IntegerField, _path: &Path) {}
+
+    fn visit_uinteger(&mut self, _field: &UIntegerField, _path: &Path) {}
+
+    fn visit_string(&mut self, _field: &StringField, _path: &Path) {}
+
+    fn visit_email(&mut self, _field: &EmailField, _path: &Path) {}
+
+    fn visit_datetime(&mut self, _field: &DatetimeField, _path: &Path) {}
+
+    fn visit_date(&mut self, _field: &DateField, _path: &Path) {}
+
+    fn visit_time(&mut self, _field: &TimeField, _path: &Path) {}
+
+    fn visit_reference(&mut self, _field: &ReferenceField, _path: &Path) {}
+}
+
+pub fn walk_field<V: SchemaVisitor + ?Sized>(visitor: &mut V, field: &FieldEnum, path: &Path) {
+    match field {
+        FieldEnum::Array(f) => visitor.visit_array(f, path),
+        FieldEnum::Object(f) => visitor.visit_object(f, path),
+        FieldEnum::Boolean(f) => visitor.visit_boolean(f, path),
+        FieldEnum::Float(f) => visitor.visit_float(f, path),
+        FieldEnum::Integer(f) => visitor.visit_integer(f, path),
+        FieldEnum::UInteger(f) => visitor.visit_uinteger(f, path),
+        FieldEnum::String(f) => visitor.visit_string(f, path),
+        FieldEnum::Email(f) => visitor.visit_email(f, path),
+        FieldEnum::Datetime(f) => visitor.visit_datetime(f, path),
+        FieldEnum::Date(f) => visitor.visit_date(f, path),
+        FieldEnum::Time(f) => visitor.visit_time(f, path),
+        FieldEnum::Reference(f) => visitor.visit_reference(f, path),
+    }
+}
+
+pub fn walk_array<V: SchemaVisitor + ?Sized>(visitor: &mut V, field: &ArrayField, path: &Path) {
+    if let Some(item) = &field.item {
+        visitor.visit_field(item, &path.child(item.name(), None, item));
+    }
+}
+
+pub fn walk_object<V: SchemaVisitor + ?Sized>(visitor: &mut V, field: &ObjectField, path: &Path) {
+    for (key, property) in &field.properties {
+        visitor.visit_field(
+            property,
+            &path.child(property.name(), Some(key.clone()), property),
+        );
+    }
+    if let Some(additional) = &field.additional_properties {
+        visitor.visit_field(additional, &path.child(additional.name(), None, additional));
+    }
+}
+
+pub fn walk<V: SchemaVisitor + ?Sized>(visitor: &mut V, schema: &FieldEnum) {
+    visitor.visit_field(schema, &Path::root(schema))
+}
+
+/// A visitor over a value together with its schema, which may change the value in place.
+/// The references are resolved, and the values deeper than [ValueVisitor::max_depth] are skipped.
+pub trait ValueVisitor {
+    fn max_depth(&self) -> usize {
+        DEFAULT_MAX_DEPTH
+    }
+
+    fn visit_value(&mut self, field: &FieldEnum, value: &mut FieldValue, path: &Path) {
+        walk_value(self, field, value, path)
+    }
+
+    fn visit_array(&mut self, field: &ArrayField, value: &mut FieldValue, path: &Path) {
+        walk_array_value(self, field, value, path)
+    }
+
+    fn visit_object(&mut self, field: &ObjectField, value: &mut FieldValue, path: &Path) {
+        walk_object_value(self, field, value, path)
+    }
+
+    fn visit_boolean(&mut self, _field: &BooleanField, _value: &mut FieldValue, _path: &Path) {}
+
+    fn visit_float(&mut self, _field: &FloatField, _value: &mut FieldValue, _path: &Path) {}
+
+    fn visit_integer(&mut self, _field: &IntegerField, _value: &mut FieldValue, _path: &Path) {}
+
+    fn visit_uinteger(&mut self, _field: &UIntegerField, _value: &mut FieldValue, _path: &Path) {}
+
+    fn visit_string(&mut self, _field: &StringField, _value: &mut FieldValue, _path: &Path) {}
+
+    fn visit_email(&mut self, _field: &EmailField, _value: &mut FieldValue, _path: &Path) {}
+
+    fn visit_datetime(&mut self, _field: &DatetimeField, _value: &mut FieldValue, _path: &Path) {}
+
+    fn visit_date(&mut self, _field: &DateField, _value: &mut FieldValue, _path: &Path) {}
+
+    fn visit_time(&mut self, _field: &TimeField, _value: &mut FieldValue, _path: &Path) {}
+
+    fn visit_unresolved(&mut self, _field: &ReferenceField, _value: &mut FieldValue, _path: &Path) {
+    }
+}
+
+pub fn walk_value<V: ValueVisitor + ?Sized>(
+    visitor: &mut V,
+    field: &FieldEnum,
+    value: &mut FieldValue,
+    path: &Path,
+) {
+    match field {
+        FieldEnum::Array(f) => visitor.visit_array(f, value, path),
+        FieldEnum::Object(f) => visitor.visit_object(f, value, path),
+        FieldEnum::Boolean(f) => visitor.visit_boolean(f, value, path),
+        FieldEnum::Float(f) => visitor.visit_float(f, value, path),
+        FieldEnum::Integer(f) => visitor.visit_integer(f, value, path),
+        FieldEnum::UInteger(f) => visitor.visit_uinteger(f, value, path),
+        FieldEnum::String(f) => visitor.visit_string(f, value, path),
+        FieldEnum::Email(f) => visitor.visit_email(f, value, path),
+        FieldEnum::Datetime(f) => visitor.visit_datetime(f, value, path),
+        FieldEnum::Date(f) => visitor.visit_date(f, value, path),
+        FieldEnum::Time(f) => visitor.visit_time(f, value, path),
+        FieldEnum::Reference(f) => visitor.visit_unresolved(f, value, path),
+    }
+}
+
+fn visit_child<V: ValueVisitor + ?Sized>(
+    visitor: &mut V,
+    field: &FieldEnum,
+    segment: String,
+    key: String,
+    value: &mut FieldValue,
+    parent: &Path,
+) {
+    if parent.depth() >= visitor.max_depth() {
+        return;
+    }
+    match parent.resolve(field) {
+        Some(resolved) => {
+            visitor.visit_value(resolved, value, &parent.child(segment, Some(key), resolved))
+        }
+        None => walk_value(
+            visitor,
+            field,
+            value,
+            &parent.child(segment, Some(key), field),
+        ),
+    }
+}
+
+pub fn walk_array_value<V: ValueVisitor + ?Sized>(
+    visitor: &mut V,
+    field: &ArrayField,
+    value: &mut FieldValue,
+    path: &Path,
+) {
+    let (Some(item), FieldValue::Array(values)) = (&field.item, value) else {
+        return;
+    };
+    for (index, value) in values.iter_mut().enumerate() {
+        visit_child(
+            visitor,
+            item,
+            index.to_string(),
+            index.to_string(),
+            value,
+            path,
+        );
+    }
+}
+
+pub fn walk_object_value<V: ValueVisitor + ?Sized>(
+    visitor: &mut V,
+    field: &ObjectField,
+    value: &mut FieldValue,
+    path: &Path,
+) {
+    let FieldValue::Object(values) = value else {
+        return;
+    };
+    for (key, value) in values.iter_mut() {
+        match (field.properties.get(key), &field.additional_properties) {
+            (Some(property), _) => {
+                visit_child(visitor, property, property.name(), key.clone(), value, path)
+            }
+            (None, Some(additional)) => {
+                visit_child(visitor, additional, key.clone(), key.clone(), value, path)
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+pub fn walk_with_value<V: ValueVisitor + ?Sized>(
+    visitor: &mut V,
+    schema: &FieldEnum,
+    value: &mut FieldValue,
+) {
+    visitor.visit_value(schema, value, &Path::root(schema))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::field::array::ArrayField;
+    use crate::core::field::object::ObjectField;
+    use crate::core::field::reference::ReferenceField;
+    use crate::core::field::string::StringField;
+    use crate::core::field::uinteger::UIntegerField;
+    use crate::core::field::FieldEnum;
+    use crate::core::value::FieldValue;
+    use crate::visitor::json::from_json;
+    use crate::visitor::validator::DEFAULT_MAX_DEPTH;
+    use crate::visitor::walk::{
+        walk, walk_array, walk_object_value, walk_with_value, Path, SchemaVisitor, ValueVisitor,
+    };
+    use serde_json::json;
+
+    fn category_schema() -> FieldEnum {
+        serde_json::from_str(
+            r#"
+            {
+                "type": "object",
+                "name": "category",
+                "properties": {
+                    "name": {"type": "string", "name": "name"},
+                    "rank": {"type": "uinteger", "name": "rank"},
+                    "tags": {
+                        "type": "array",
+                        "name": "tags",
+                        "item": {"type": "string", "name": "tag"}
+                    },
+                    "children": {
+                        "type": "array",
+                        "name": "children",
+                        "item": {
+                            "type": "reference",
+                            "name": "child",
+                            "reference": "category"
+                        }
+                    }
+                },
+                "additionalProperties": {"type": "string", "name": "label"}
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[derive(Default)]
+    struct Paths {
+        paths: Vec<String>,
+        skip_arrays: bool,
+    }
+
+    impl SchemaVisitor for Paths {
+        fn visit_array(&mut self, field: &ArrayField, path: &Path) {
+            self.paths.push(format!("{} []", path));
+            if !self.skip_arrays {
+                walk_array(self, field, path)
+            }
+        }
+
+        fn visit_string(&mut self, _field: &StringField, path: &Path) {
+            self.paths.push(path.to_string());
+        }
+
+        fn visit_reference(&mut self, field: &ReferenceField, path: &Path) {
+            self.paths.push(format!("{} -> {}", path, field.reference));
+        }
+    }
+
+    #[test]
+    fn test_schema_visitor() {
+        let schema = category_schema();
+        let mut visitor = Paths::default();
+        walk(&mut visitor, &schema);
+        assert_eq!(
+            visitor.paths,
+            vec![
+                "category/children []",
+                "category/children/child -> category",
+                "category/name",
+                "category/tags []",
+                "category/tags/tag",
+                "category/label",
+            ]
+        );
+
+        let mut visitor = Paths {
+            skip_arrays: true,
+            ..Default::default()
+        };
+        walk(&mut visitor, &schema);
+        assert_eq!(
+            visitor.paths,
+            vec![
+                "category/children []",
+                "category/name",
+                "category/tags []",
+                "category/label",
+            ]
+        );
+    }
+
+    struct Values {
+        values: Vec<(String, FieldValue)>,
+        objects: usize,
+        max_depth: usize,
+    }
+
+    impl Values {
+        fn new(max_depth: usize) -> Self {
+            Values {
+                values: vec![],
+                objects: 0,
+                max_depth,
+            }
+        }
+    }
+
+    impl ValueVisitor for Values {
+        fn max_depth(&self) -> usize {
+            self.max_depth
+        }
+
+        fn visit_object(&mut self, field: &ObjectField, value: &mut FieldValue, path: &Path) {
+            self.objects += 1;
+            walk_object_value(self, field, value, path)
+        }
+
+        fn visit_uinteger(&mut self, _field: &UIntegerField, value: &mut FieldValue, path: &Path) {
+            self.values.push((path.to_string(), value.clone()));
+        }
+
+        fn visit_string(&mut self, _field: &StringField, value: &mut FieldValue, path: &Path) {
+            self.values.push((path.to_string(), value.clone()));
+            if let FieldValue::String(s) = value {
+                *s = s.to_uppercase();
+            }
+        }
+    }
+
+    #[test]
+    fn test_value_visitor() {
+        let schema = category_schema();
+        let mut value = from_json(
+            &schema,
+            &json!({
+                "name": "books",
+                "rank": 1,
+                "tags": ["paper"],
+                "note": "shelf",
+                "unknown": [],
+                "children": [
+                    {"name": "novels", "rank": "first"},
+                    {"children": [{"name": "classics"}]}
+                ]
+            }),
+        );
+        let mut visitor = Values::new(DEFAULT_MAX_DEPTH);
+        walk_with_value(&mut visitor, &schema, &mut value);
+        assert_eq!(visitor.objects, 4);
+        assert_eq!(
+            visitor
+                .values
+                .iter()
+                .map(|(path, value)| (path.as_str(), value.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("category/children/0/name", "novels".to_string()),
+                ("category/children/0/rank", "first".to_string()),
+                (
+                    "category/children/1/children/0/name",
+                    "classics".to_string()
+                ),
+                ("category/name", "books".to_string()),
+                ("category/note", "shelf".to_string()),
+                ("category/rank", "1".to_string()),
+                ("category/tags/0", "paper".to_string()),
+                ("category/unknown", "Array(...)".to_string()),
+            ]
+        );
+        let FieldValue::Object(o) = &value else {
+            panic!("object is expected")
+        };
+        assert_eq!(o["name"], FieldValue::String("BOOKS".to_string()));
+
+        let mut visitor = Values::new(3);
+        walk_with_value(&mut visitor, &schema, &mut value);
+        assert_eq!(visitor.objects, 3);
+        assert_eq!(
+            visitor
+                .values
+                .iter()
+                .map(|(path, _)| path.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "category/name",
+                "category/note",
+                "category/rank",
+                "category/tags/0",
+                "category/unknown",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_path() {
+        let schema = category_schema();
+        let FieldEnum::Object(o) = &schema else {
+            panic!("object is expected")
+        };
+        let root = Path::root(&schema);
+        let tags = root.child(
+            "tags".to_string(),
+            Some("tags".to_string()),
+            &o.properties["tags"],
+        );
+        let tag = tags.child(
+            "0".to_string(),
+            Some("0".to_string()),
+            &o.properties["name"],
+        );
+        assert_eq!(tag.to_string(), "category/tags/0");
+        assert_eq!(tag.segments(), vec!["category", "tags", "0"]);
+        assert_eq!(tag.segment(), "0");
+        assert_eq!(tag.key(), Some("0"));
+        assert_eq!(root.key(), None);
+        assert_eq!(tag.depth(), 3);
+        assert_eq!(tag.parent().unwrap().segment(), "tags");
+        assert_eq!(tag.find("category").unwrap().name(), "category");
+        assert_eq!(tag.find("tags").unwrap().name(), "tags");
+        assert!(tag.find("children").is_none());
+        // only arrays and objects can be referred to
+        assert!(tag.find("name").is_none());
+        assert_eq!(
+            tag.resolve(&o.properties["children"]).unwrap().name(),
+            "children"
+        );
+    }
+}
@@ -1,8 +1,7 @@
 mod reflect;
 
-use crate::reflect::reflect::impl_reflect_macro;
+use crate::reflect::impl_reflect_macro;
 use proc_macro::TokenStream;
-use syn;
 
 #[proc_macro_derive(Reflect, attributes(reflect))]
 pub fn reflect_macro_derive(input: TokenStream) -> TokenStream {
@@ -1,5 +1,6 @@
-use avocado_schema::core::value::{FieldValue, Reflect};
+use avocado_schema::core::value::{FieldValue, FieldValueRef, Reflect};
 use avocado_schema_derive::Reflect;
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 
 #[allow(dead_code)]
@@ -32,5 +33,23 @@ fn main() {
             ("lastName".to_string(), FieldValue::String("Li".to_string())),
             ("age".to_string(), FieldValue::UInteger(30))
         ]))
-    )
+    );
+    assert_eq!(
+        client.field_value_ref(),
+        FieldValueRef::Object(BTreeMap::from([
+            (
+                Cow::Borrowed("firstName"),
+                FieldValueRef::String(Cow::Borrowed(&client.first_name))
+            ),
+            (
+                Cow::Borrowed("lastName"),
+                FieldValueRef::String(Cow::Borrowed(&client.last_name))
+            ),
+            (Cow::Borrowed("age"), FieldValueRef::UInteger(30))
+        ]))
+    );
+    assert!(matches!(
+        client.field_value_ref(),
+        FieldValueRef::Object(o) if matches!(o.get("firstName"), Some(FieldValueRef::String(Cow::Borrowed(_))))
+    ));
 }